@@ -0,0 +1,114 @@
+use crate::math;
+use crate::vector::Vec3;
+
+/// Exponential moving average: each `update` moves `value` a fraction `alpha`
+/// of the way towards the new sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpSmooth {
+    pub value: f32,
+    pub alpha: f32,
+}
+
+impl ExpSmooth {
+    pub fn new(value: f32, alpha: f32) -> ExpSmooth {
+        ExpSmooth { value, alpha }
+    }
+
+    pub fn update(&mut self, sample: f32) -> f32 {
+        self.value = math::lerp(self.value, sample, self.alpha);
+        self.value
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpSmoothVec3 {
+    pub value: Vec3,
+    pub alpha: f32,
+}
+
+impl ExpSmoothVec3 {
+    pub fn new(value: Vec3, alpha: f32) -> ExpSmoothVec3 {
+        ExpSmoothVec3 { value, alpha }
+    }
+
+    pub fn update(&mut self, sample: Vec3) -> Vec3 {
+        self.value = self.value.lerp_unclamped(&sample, self.alpha);
+        self.value
+    }
+}
+
+/// Advances a damped spring pulling `current` towards `target` by one step of `dt`
+/// (semi-implicit Euler), updating `velocity` in place and returning the new position.
+pub fn spring(
+    current: Vec3,
+    velocity: &mut Vec3,
+    target: Vec3,
+    stiffness: f32,
+    damping: f32,
+    dt: f32,
+) -> Vec3 {
+    let pull = target.sub(&current).mul_scalar(stiffness);
+    let drag = velocity.mul_scalar(damping);
+    *velocity = velocity.add(&pull.sub(&drag).mul_scalar(dt));
+    current.add(&velocity.mul_scalar(dt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp_smooth_converges() {
+        let mut filter = ExpSmooth::new(0.0, 0.2);
+        for _ in 0..100 {
+            filter.update(5.0);
+        }
+        assert!((filter.value - 5.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_exp_smooth_single_step() {
+        let mut filter = ExpSmooth::new(0.0, 0.25);
+        assert_eq!(filter.update(4.0), 1.0);
+        assert_eq!(filter.update(4.0), 1.75);
+    }
+
+    #[test]
+    fn test_exp_smooth_vec3_converges() {
+        let target = Vec3::new(1.0, -2.0, 3.0);
+        let mut filter = ExpSmoothVec3::new(Vec3::new(0.0, 0.0, 0.0), 0.3);
+        for _ in 0..100 {
+            filter.update(target);
+        }
+        assert!(filter.value.sub(&target).length() < 1e-4);
+    }
+
+    #[test]
+    fn test_spring_overdamped_converges_monotonically() {
+        let target = Vec3::new(1.0, 0.0, 0.0);
+        let mut position = Vec3::new(0.0, 0.0, 0.0);
+        let mut velocity = Vec3::new(0.0, 0.0, 0.0);
+        let mut previous = target.sub(&position).length();
+        for _ in 0..600 {
+            position = spring(position, &mut velocity, target, 10.0, 20.0, 1.0 / 60.0);
+            let distance = target.sub(&position).length();
+            assert!(distance <= previous);
+            assert!(position.x <= target.x);
+            previous = distance;
+        }
+        assert!(previous < 1e-2);
+    }
+
+    #[test]
+    fn test_spring_underdamped_oscillates() {
+        let target = Vec3::new(1.0, 0.0, 0.0);
+        let mut position = Vec3::new(0.0, 0.0, 0.0);
+        let mut velocity = Vec3::new(0.0, 0.0, 0.0);
+        let mut overshot = false;
+        for _ in 0..120 {
+            position = spring(position, &mut velocity, target, 100.0, 1.0, 1.0 / 60.0);
+            overshot |= position.x > target.x;
+        }
+        assert!(overshot);
+    }
+}
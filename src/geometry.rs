@@ -0,0 +1,391 @@
+use crate::matrix::Mat4;
+use crate::vector::{Vec2, Vec3, Vec4};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray {
+    pub fn new(origin: Vec3, direction: Vec3) -> Ray {
+        Ray { origin, direction }
+    }
+
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin.add(&self.direction.mul_scalar(t))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    pub fn new(min: Vec3, max: Vec3) -> Aabb {
+        Aabb { min, max }
+    }
+
+    pub fn center(&self) -> Vec3 {
+        self.min.add(&self.max).mul_scalar(0.5)
+    }
+
+    pub fn half_extents(&self) -> Vec3 {
+        self.max.sub(&self.min).mul_scalar(0.5)
+    }
+}
+
+/// Six clip planes stored as `(a, b, c, d)` with `a*x + b*y + c*z + d >= 0` inside,
+/// in the order left, right, bottom, top, near, far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    pub planes: [Vec4; 6],
+}
+
+impl Frustum {
+    /// Extracts the planes from a view-projection matrix (Gribb-Hartmann), assuming
+    /// column vectors and clip-space depth in [-1, 1].
+    pub fn from_view_projection(vp: &Mat4) -> Frustum {
+        let row = |i: usize| Vec4::new(vp.data[i][0], vp.data[i][1], vp.data[i][2], vp.data[i][3]);
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+        let planes = [
+            w.add(&x),
+            w.sub(&x),
+            w.add(&y),
+            w.sub(&y),
+            w.add(&z),
+            w.sub(&z),
+        ];
+        Frustum {
+            planes: planes.map(|plane| {
+                let len = Vec3::new(plane.x, plane.y, plane.z).length();
+                if len > 0.0 {
+                    plane.mul_scalar(1.0 / len)
+                } else {
+                    plane
+                }
+            }),
+        }
+    }
+
+    /// Conservative test: `false` only when the box lies entirely outside one plane.
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            let positive = Vec3::new(
+                if plane.x >= 0.0 {
+                    aabb.max.x
+                } else {
+                    aabb.min.x
+                },
+                if plane.y >= 0.0 {
+                    aabb.max.y
+                } else {
+                    aabb.min.y
+                },
+                if plane.z >= 0.0 {
+                    aabb.max.z
+                } else {
+                    aabb.min.z
+                },
+            );
+            plane.x * positive.x + plane.y * positive.y + plane.z * positive.z + plane.w >= 0.0
+        })
+    }
+}
+
+/// Walks the grid cells pierced by `ray` in order (Amanatides & Woo), calling
+/// `visit` for each one. Stops after `max_steps` cells or as soon as `visit`
+/// returns `true`.
+pub fn dda_traverse(
+    ray: &Ray,
+    cell_size: f32,
+    max_steps: u32,
+    mut visit: impl FnMut((i32, i32, i32)) -> bool,
+) {
+    let (cx, cy, cz) = ray.origin.cell(cell_size);
+    let mut cell = [cx, cy, cz];
+    let origin = [ray.origin.x, ray.origin.y, ray.origin.z];
+    let direction = [ray.direction.x, ray.direction.y, ray.direction.z];
+
+    let mut step = [0i32; 3];
+    let mut t_max = [f32::INFINITY; 3];
+    let mut t_delta = [f32::INFINITY; 3];
+    for axis in 0..3 {
+        if direction[axis] > 0.0 {
+            step[axis] = 1;
+            let boundary = (cell[axis] + 1) as f32 * cell_size;
+            t_max[axis] = (boundary - origin[axis]) / direction[axis];
+            t_delta[axis] = cell_size / direction[axis];
+        } else if direction[axis] < 0.0 {
+            step[axis] = -1;
+            let boundary = cell[axis] as f32 * cell_size;
+            t_max[axis] = (boundary - origin[axis]) / direction[axis];
+            t_delta[axis] = -cell_size / direction[axis];
+        }
+    }
+
+    for _ in 0..max_steps {
+        if visit((cell[0], cell[1], cell[2])) {
+            return;
+        }
+        let axis = if t_max[0] < t_max[1] && t_max[0] < t_max[2] {
+            0
+        } else if t_max[1] < t_max[2] {
+            1
+        } else {
+            2
+        };
+        if t_max[axis].is_infinite() {
+            return;
+        }
+        cell[axis] += step[axis];
+        t_max[axis] += t_delta[axis];
+    }
+}
+
+/// Orientation predicate: twice the signed area of triangle `abc`. Positive when
+/// `a`, `b`, `c` turn counter-clockwise, negative when clockwise, zero when collinear.
+pub fn orient2d(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Signed area of a simple polygon (shoelace formula): positive for
+/// counter-clockwise winding, negative for clockwise.
+pub fn polygon_area(points: &[Vec2]) -> f32 {
+    if points.len() < 3 {
+        return 0.0;
+    }
+    // Offsetting by the first vertex keeps the cross terms small for polygons
+    // far from the origin.
+    let origin = points[0];
+    let mut twice_area = 0.0;
+    for pair in points[1..].windows(2) {
+        let a = pair[0].sub(&origin);
+        let b = pair[1].sub(&origin);
+        twice_area += a.x * b.y - b.x * a.y;
+    }
+    twice_area * 0.5
+}
+
+/// Even-odd point-in-polygon test, casting a ray towards +x.
+///
+/// Edges are treated as half-open: a point exactly on a left or bottom edge is
+/// inside and one on a right or top edge is outside, so a point on an edge shared
+/// by two adjacent polygons belongs to exactly one of them.
+pub fn point_in_polygon(p: Vec2, polygon: &[Vec2]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[j];
+        if (a.y > p.y) != (b.y > p.y) {
+            let crossing_x = a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y);
+            if p.x < crossing_x {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Average of the three vertices.
+pub fn triangle_centroid(a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    (a + b + c) * (1.0 / 3.0)
+}
+
+/// Center of the inscribed circle: the vertices weighted by the length of the
+/// opposite edge. A triangle collapsed to a single point returns that point.
+pub fn triangle_incenter(a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    let wa = (c - b).length();
+    let wb = (a - c).length();
+    let wc = (b - a).length();
+    let perimeter = wa + wb + wc;
+    if perimeter == 0.0 {
+        return a;
+    }
+    (a * wa + b * wb + c * wc) * (1.0 / perimeter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::EPSILON;
+
+    #[test]
+    fn test_ray_at() {
+        let ray = Ray::new(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+        assert_eq!(ray.at(1.5), Vec3::new(1.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn test_orient2d() {
+        let a = Vec2::new(0.0, 0.0);
+        let b = Vec2::new(1.0, 0.0);
+        assert_eq!(orient2d(a, b, Vec2::new(0.0, 1.0)), 1.0);
+        assert_eq!(orient2d(a, b, Vec2::new(0.0, -1.0)), -1.0);
+        assert_eq!(orient2d(a, b, Vec2::new(3.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_polygon_area() {
+        let square = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        assert_eq!(polygon_area(&square), 1.0);
+
+        let mut reversed = square;
+        reversed.reverse();
+        assert_eq!(polygon_area(&reversed), -1.0);
+    }
+
+    #[test]
+    fn test_polygon_area_offset_and_degenerate() {
+        let far_triangle = [
+            Vec2::new(1000.0, 1000.0),
+            Vec2::new(1004.0, 1000.0),
+            Vec2::new(1000.0, 1003.0),
+        ];
+        assert_eq!(polygon_area(&far_triangle), 6.0);
+        assert_eq!(polygon_area(&far_triangle[..2]), 0.0);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        let quad = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert!(point_in_polygon(Vec2::new(1.0, 1.0), &quad));
+        assert!(!point_in_polygon(Vec2::new(3.0, 1.0), &quad));
+        assert!(!point_in_polygon(Vec2::new(1.0, -0.5), &quad));
+        assert!(point_in_polygon(Vec2::new(1.999, 1.999), &quad));
+        assert!(!point_in_polygon(Vec2::new(2.001, 2.001), &quad));
+    }
+
+    #[test]
+    fn test_point_in_polygon_edges() {
+        let quad = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert!(point_in_polygon(Vec2::new(0.0, 1.0), &quad));
+        assert!(!point_in_polygon(Vec2::new(2.0, 1.0), &quad));
+        assert!(!point_in_polygon(Vec2::new(1.0, 1.0), &[]));
+    }
+
+    #[test]
+    fn test_aabb_center_and_extents() {
+        let aabb = Aabb::new(Vec3::new(-1.0, 0.0, 2.0), Vec3::new(3.0, 2.0, 4.0));
+        assert_eq!(aabb.center(), Vec3::new(1.0, 1.0, 3.0));
+        assert_eq!(aabb.half_extents(), Vec3::new(2.0, 1.0, 1.0));
+    }
+
+    fn test_frustum() -> Frustum {
+        // Right-handed perspective, 90 degree fov, aspect 1, near 1, far 10.
+        let projection = Mat4 {
+            data: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, -11.0 / 9.0, -20.0 / 9.0],
+                [0.0, 0.0, -1.0, 0.0],
+            ],
+        };
+        Frustum::from_view_projection(&projection)
+    }
+
+    #[test]
+    fn test_frustum_planes() {
+        let frustum = test_frustum();
+        let near = frustum.planes[4];
+        let far = frustum.planes[5];
+        assert!((near.z + 1.0).abs() < EPSILON && (near.w + 1.0).abs() < EPSILON);
+        assert!((far.z - 1.0).abs() < EPSILON && (far.w - 10.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_frustum_intersects_aabb() {
+        let frustum = test_frustum();
+        let inside = Aabb::new(Vec3::new(-0.5, -0.5, -5.5), Vec3::new(0.5, 0.5, -4.5));
+        let behind = Aabb::new(Vec3::new(-0.5, -0.5, 1.5), Vec3::new(0.5, 0.5, 2.5));
+        let beside = Aabb::new(Vec3::new(20.0, -0.5, -5.5), Vec3::new(21.0, 0.5, -4.5));
+        let straddling = Aabb::new(Vec3::new(-0.5, -0.5, -12.0), Vec3::new(0.5, 0.5, -8.0));
+        assert!(frustum.intersects_aabb(&inside));
+        assert!(!frustum.intersects_aabb(&behind));
+        assert!(!frustum.intersects_aabb(&beside));
+        assert!(frustum.intersects_aabb(&straddling));
+    }
+
+    #[test]
+    fn test_dda_traverse_axis_aligned() {
+        let ray = Ray::new(Vec3::new(0.5, 0.5, 0.5), Vec3::new(1.0, 0.0, 0.0));
+        let mut cells = Vec::new();
+        dda_traverse(&ray, 1.0, 4, |cell| {
+            cells.push(cell);
+            false
+        });
+        assert_eq!(cells, vec![(0, 0, 0), (1, 0, 0), (2, 0, 0), (3, 0, 0)]);
+
+        let ray = Ray::new(Vec3::new(0.5, 0.5, 0.5), Vec3::new(0.0, 0.0, -1.0));
+        let mut cells = Vec::new();
+        dda_traverse(&ray, 1.0, 3, |cell| {
+            cells.push(cell);
+            false
+        });
+        assert_eq!(cells, vec![(0, 0, 0), (0, 0, -1), (0, 0, -2)]);
+    }
+
+    #[test]
+    fn test_dda_traverse_stops_on_hit() {
+        let ray = Ray::new(Vec3::new(0.5, 0.5, 0.5), Vec3::new(1.0, 0.0, 0.0));
+        let mut cells = Vec::new();
+        dda_traverse(&ray, 1.0, 100, |cell| {
+            cells.push(cell);
+            cell == (2, 0, 0)
+        });
+        assert_eq!(cells, vec![(0, 0, 0), (1, 0, 0), (2, 0, 0)]);
+    }
+
+    #[test]
+    fn test_dda_traverse_diagonal() {
+        let ray = Ray::new(Vec3::new(0.5, 0.25, 0.5), Vec3::new(1.0, 1.0, 0.0));
+        let mut cells = Vec::new();
+        dda_traverse(&ray, 1.0, 4, |cell| {
+            cells.push(cell);
+            false
+        });
+        assert_eq!(cells, vec![(0, 0, 0), (1, 0, 0), (1, 1, 0), (2, 1, 0)]);
+    }
+
+    #[test]
+    fn test_triangle_centroid() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 0.0, 0.0);
+        let c = Vec3::new(0.0, 1.0, 0.0);
+        let centroid = triangle_centroid(a, b, c);
+        assert!((centroid.x - 1.0 / 3.0).abs() < EPSILON);
+        assert!((centroid.y - 1.0 / 3.0).abs() < EPSILON);
+        assert_eq!(centroid.z, 0.0);
+    }
+
+    #[test]
+    fn test_triangle_incenter() {
+        // 3-4-5 right triangle: inradius (3 + 4 - 5) / 2 = 1.
+        let a = Vec3::new(0.0, 0.0, 2.0);
+        let b = Vec3::new(3.0, 0.0, 2.0);
+        let c = Vec3::new(0.0, 4.0, 2.0);
+        let incenter = triangle_incenter(a, b, c);
+        assert!(incenter.sub(&Vec3::new(1.0, 1.0, 2.0)).length() < EPSILON);
+
+        let p = Vec3::new(5.0, 5.0, 5.0);
+        assert_eq!(triangle_incenter(p, p, p), p);
+    }
+}
@@ -3,6 +3,10 @@
 #[cfg(not(feature = "std"))]
 extern crate libm;
 
-mod math;
-mod matrix;
-mod vector;
+pub mod filter;
+pub mod geometry;
+pub mod math;
+pub mod matrix;
+pub mod noise;
+pub mod quaternion;
+pub mod vector;
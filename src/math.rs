@@ -4,7 +4,7 @@
 extern crate libm;
 
 pub const PI: f32 = std::f32::consts::PI;
-pub const TAU: f32 = 6.28318530718;
+pub const TAU: f32 = std::f32::consts::TAU;
 pub const E: f32 = std::f32::consts::E;
 pub const SQRT_2: f32 = std::f32::consts::SQRT_2;
 pub const LN_2: f32 = std::f32::consts::LN_2;
@@ -43,9 +43,52 @@ pub fn lerp(start: f32, end: f32, t: f32) -> f32 {
 pub fn approx_equal(a: f32, b: f32, epsilon: f32) -> bool {
     (a - b).abs() < epsilon
 }
+/// `EPSILON` scaled to the size of the values being compared, never below the
+/// absolute `EPSILON` so comparisons near zero still have some slack.
+pub fn epsilon_scaled(magnitude: f32) -> f32 {
+    EPSILON * max(1.0, magnitude.abs())
+}
+/// Relative comparison: tolerance grows with the larger of the two magnitudes.
+pub fn approx_equal_relative(a: f32, b: f32) -> bool {
+    (a - b).abs() <= epsilon_scaled(max(a.abs(), b.abs()))
+}
 pub fn approx_zero(a: f32, epsilon: f32) -> bool {
     a.abs() < epsilon
 }
+pub fn signum(value: f32) -> f32 {
+    if value > 0.0 {
+        1.0
+    } else if value < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+/// Wraps a grid index into `0..n` (e.g. a coordinate from `Vec3::cell` on a tiling
+/// world). Unlike `%`, negative indices wrap around: `wrap_index(-1, 4) == 3`.
+///
+/// Panics if `n == 0`; `n` must be positive.
+pub fn wrap_index(i: i32, n: i32) -> i32 {
+    debug_assert!(n > 0, "wrap_index needs a positive length");
+    i.rem_euclid(n)
+}
+/// FNV-1a over the bit patterns of `values`, stable across runs and platforms.
+/// `-0.0` is hashed as `0.0` so values that compare equal hash equal; NaNs hash
+/// by payload.
+pub fn hash_bits(values: impl IntoIterator<Item = f32>) -> u64 {
+    values
+        .into_iter()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, value| {
+            let value = if value == 0.0 { 0.0 } else { value };
+            value
+                .to_bits()
+                .to_le_bytes()
+                .iter()
+                .fold(hash, |hash, &byte| {
+                    (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+                })
+        })
+}
 pub fn degrees_to_radians(degrees: f32) -> f32 {
     degrees * DEG_TO_RAD
 }
@@ -112,12 +155,50 @@ mod tests {
         assert!(!approx_equal(1.0, 1.1, EPSILON));
     }
 
+    #[test]
+    fn epsilon_scaled_grows_with_magnitude() {
+        assert_eq!(epsilon_scaled(0.5), EPSILON);
+        assert_eq!(epsilon_scaled(-1000.0), EPSILON * 1000.0);
+    }
+
+    #[test]
+    fn approx_equal_relative_tolerates_large_values() {
+        let a = 1.0e8_f32;
+        let b = f32::from_bits(a.to_bits() + 1);
+        assert!(!approx_equal(a, b, EPSILON));
+        assert!(approx_equal_relative(a, b));
+        assert!(!approx_equal_relative(a, a * 1.001));
+        assert!(approx_equal_relative(0.0, 1e-6));
+    }
+
     #[test]
     fn approx_zero_within_epsilon() {
         assert!(approx_zero(0.000001, EPSILON));
         assert!(!approx_zero(0.1, EPSILON));
     }
 
+    #[test]
+    fn signum_returns_sign_or_zero() {
+        assert_eq!(signum(-3.5), -1.0);
+        assert_eq!(signum(0.0), 0.0);
+        assert_eq!(signum(2.0), 1.0);
+    }
+
+    #[test]
+    fn wrap_index_is_positive_modulo() {
+        assert_eq!(wrap_index(-1, 4), 3);
+        assert_eq!(wrap_index(5, 4), 1);
+        assert_eq!(wrap_index(-8, 4), 0);
+        assert_eq!(wrap_index(2, 4), 2);
+    }
+
+    #[test]
+    fn hash_bits_merges_signed_zero() {
+        assert_eq!(hash_bits([1.0, 2.0]), hash_bits([1.0, 2.0]));
+        assert_eq!(hash_bits([0.0]), hash_bits([-0.0]));
+        assert_ne!(hash_bits([0.0]), hash_bits([1.0]));
+    }
+
     #[test]
     fn degrees_to_radians_conversion() {
         assert_eq!(degrees_to_radians(180.0), PI);
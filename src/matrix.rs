@@ -6,11 +6,55 @@ use core::arch::x86_64::*;
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::*;
 
-#[derive(Debug, Clone, PartialEq)]
+use core::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use crate::geometry::Aabb;
+use crate::math::{self, EPSILON};
+use crate::quaternion::Quat;
+use crate::vector::{Vec2, Vec3, Vec4};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Mat4 {
     pub data: [[f32; 4]; 4],
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3 {
+    pub data: [[f32; 3]; 3],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat2 {
+    pub data: [[f32; 2]; 2],
+}
+
+/// Clip-space depth range targeted by projection matrices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthRange {
+    /// OpenGL convention: near maps to -1, far to 1.
+    NegOneToOne,
+    /// Direct3D/Vulkan/Metal convention: near maps to 0, far to 1.
+    ZeroToOne,
+}
+
+/// Layout of a flat 16-element array. `Mat4` itself always stores rows in
+/// `data`; this only describes how values are laid out when converting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MajorOrder {
+    /// Element `(row, col)` is at index `row * 4 + col`.
+    RowMajor,
+    /// Element `(row, col)` is at index `col * 4 + row` (OpenGL, glTF).
+    ColMajor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvertError {
+    /// The determinant is (numerically) zero.
+    Singular,
+    /// The matrix contains a NaN or infinite element.
+    NonFinite,
+}
+
 impl Mat4 {
     pub fn new_identity() -> Mat4 {
         Mat4 {
@@ -39,7 +83,70 @@ impl Mat4 {
         result
     }
 
+    pub fn sub(&self, other: &Mat4) -> Mat4 {
+        let mut result = Mat4::new_zero();
+        for i in 0..4 {
+            for j in 0..4 {
+                result.data[i][j] = self.data[i][j] - other.data[i][j];
+            }
+        }
+        result
+    }
+
+    pub fn mul_scalar(&self, scalar: f32) -> Mat4 {
+        let mut result = *self;
+        for value in result.data.iter_mut().flatten() {
+            *value *= scalar;
+        }
+        result
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Mat4::new_identity()
+    }
+
+    /// Upper-left 3x3 block, see [`Mat3::from_mat4`].
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_mat4(self)
+    }
+
+    /// Renders the matrix as a bordered ASCII grid with `r0`..`r3` row and
+    /// `c0`..`c3` column labels, for debug output.
+    #[cfg(feature = "std")]
+    pub fn debug_table(&self) -> String {
+        let border = format!("+----{}+\n", "+------------".repeat(4));
+        let mut table = border.clone();
+        table.push_str("|    ");
+        for col in 0..4 {
+            table.push_str(&format!("| {:>10} ", format!("c{col}")));
+        }
+        table.push_str("|\n");
+        table.push_str(&border);
+        for (row, values) in self.data.iter().enumerate() {
+            table.push_str(&format!("| r{row} "));
+            for value in values {
+                table.push_str(&format!("| {value:>10.4} "));
+            }
+            table.push_str("|\n");
+        }
+        table.push_str(&border);
+        table
+    }
+
+    /// Deterministic hash of the element bit patterns, for use as a cache key.
+    pub fn bit_hash(&self) -> u64 {
+        math::hash_bits(self.data.iter().flatten().copied())
+    }
+
+    /// Matrix product. Returns the other operand unchanged when either side is
+    /// exactly the identity.
     pub fn mul(&self, other: &Mat4) -> Mat4 {
+        if other.is_identity() {
+            return *self;
+        }
+        if self.is_identity() {
+            return *other;
+        }
         let mut result = Mat4::new_zero();
         for i in 0..4 {
             for j in 0..4 {
@@ -52,67 +159,568 @@ impl Mat4 {
         result
     }
 
+    /// `self * other^T` without building the transpose: entry `(i, j)` is the dot
+    /// product of row `i` of `self` with row `j` of `other`.
+    pub fn mul_transpose(&self, other: &Mat4) -> Mat4 {
+        let mut result = Mat4::new_zero();
+        for (out, row) in result.data.iter_mut().zip(self.data.iter()) {
+            for (value, other_row) in out.iter_mut().zip(other.data.iter()) {
+                *value = row.iter().zip(other_row.iter()).map(|(a, b)| a * b).sum();
+            }
+        }
+        result
+    }
+
+    /// Transforms every point in place, loading the matrix into registers once for
+    /// the whole slice.
+    pub fn transform_slice(&self, points: &mut [Vec4]) {
+        let apply = self.column_transform();
+        for point in points.iter_mut() {
+            *point = apply(point);
+        }
+    }
+
+    /// Writes `self * points[i]` into `out[i]`. Panics if the lengths differ.
+    pub fn transform_slice_into(&self, points: &[Vec4], out: &mut [Vec4]) {
+        assert_eq!(points.len(), out.len());
+        let apply = self.column_transform();
+        for (point, result) in points.iter().zip(out.iter_mut()) {
+            *result = apply(point);
+        }
+    }
+
+    /// Returns `v -> self * v` with the matrix columns preloaded, computing each
+    /// point as `col0 * x + col1 * y + col2 * z + col3 * w`.
+    #[cfg(target_arch = "x86_64")]
+    fn column_transform(&self) -> impl Fn(&Vec4) -> Vec4 + '_ {
+        let cols = self
+            .transpose()
+            .data
+            .map(|col| unsafe { _mm_loadu_ps(col.as_ptr()) });
+        move |v| {
+            let mut result = [0.0; 4];
+            unsafe {
+                let xy = _mm_add_ps(
+                    _mm_mul_ps(cols[0], _mm_set1_ps(v.x)),
+                    _mm_mul_ps(cols[1], _mm_set1_ps(v.y)),
+                );
+                let zw = _mm_add_ps(
+                    _mm_mul_ps(cols[2], _mm_set1_ps(v.z)),
+                    _mm_mul_ps(cols[3], _mm_set1_ps(v.w)),
+                );
+                _mm_storeu_ps(result.as_mut_ptr(), _mm_add_ps(xy, zw));
+            }
+            Vec4::new(result[0], result[1], result[2], result[3])
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn column_transform(&self) -> impl Fn(&Vec4) -> Vec4 + '_ {
+        let cols = self
+            .transpose()
+            .data
+            .map(|col| unsafe { vld1q_f32(col.as_ptr()) });
+        move |v| {
+            let mut result = [0.0; 4];
+            unsafe {
+                let mut acc = vmulq_n_f32(cols[0], v.x);
+                acc = vfmaq_n_f32(acc, cols[1], v.y);
+                acc = vfmaq_n_f32(acc, cols[2], v.z);
+                acc = vfmaq_n_f32(acc, cols[3], v.w);
+                vst1q_f32(result.as_mut_ptr(), acc);
+            }
+            Vec4::new(result[0], result[1], result[2], result[3])
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn column_transform(&self) -> impl Fn(&Vec4) -> Vec4 + '_ {
+        move |v| self.mul_vec4(v)
+    }
+
+    /// `self` multiplied by itself `n` times, by repeated squaring (O(log n)
+    /// products). `n == 0` gives the identity.
+    pub fn powi(&self, n: u32) -> Mat4 {
+        let mut result = Mat4::new_identity();
+        let mut base = *self;
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.mul(&base);
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+
+    /// Transforms a column vector: each output component is a row of the matrix
+    /// dotted with `v`.
+    pub fn mul_vec4(&self, v: &Vec4) -> Vec4 {
+        let row = |i: usize| {
+            let r = &self.data[i];
+            r[0] * v.x + r[1] * v.y + r[2] * v.z + r[3] * v.w
+        };
+        Vec4::new(row(0), row(1), row(2), row(3))
+    }
+
+    /// SSE matrix product: each output row is accumulated as
+    /// `sum_k self[i][k] * other.row(k)` and stored in one go.
     #[cfg(target_arch = "x86_64")]
     pub fn mul_simd(&self, other: &Mat4) -> Mat4 {
         let mut result = Mat4::new_zero();
-        for i in 0..4 {
+        for (out, row) in result.data.iter_mut().zip(self.data.iter()) {
             unsafe {
-                for j in 0..4 {
-                    let mut dot_product = _mm_set1_ps(0.0);
-                    for k in 0..4 {
-                        let a_element = _mm_set1_ps(self.data[i][k]);
-                        let b = _mm_loadu_ps(other.data[k].as_ptr());
-                        let product = _mm_mul_ps(a_element, b);
-                        dot_product = _mm_add_ps(dot_product, product);
-                    }
-                    let mut temp = [0.0; 4];
-                    _mm_storeu_ps(temp.as_mut_ptr(), dot_product);
-                    result.data[i][j] = temp[j];
+                let mut acc = _mm_setzero_ps();
+                for (&a, b) in row.iter().zip(other.data.iter()) {
+                    let product = _mm_mul_ps(_mm_set1_ps(a), _mm_loadu_ps(b.as_ptr()));
+                    acc = _mm_add_ps(acc, product);
                 }
+                _mm_storeu_ps(out.as_mut_ptr(), acc);
             }
         }
         result
     }
 
+    /// NEON matrix product, accumulating each output row with one fused
+    /// multiply-add per row of `other`.
     #[cfg(target_arch = "aarch64")]
     pub fn mul_neon(&self, other: &Mat4) -> Mat4 {
         let mut result = Mat4::new_zero();
-        for i in 0..4 {
+        for (out, row) in result.data.iter_mut().zip(self.data.iter()) {
             unsafe {
-                for j in 0..4 {
-                    let mut dot_product = vdupq_n_f32(0.0);
-                    for k in 0..4 {
-                        let a_element = vdupq_n_f32(self.data[i][k]);
-                        let b = vld1q_f32(other.data[k].as_ptr());
-                        let product = vmulq_f32(a_element, b);
-                        dot_product = vaddq_f32(dot_product, product);
-                    }
-                    let mut temp = [0.0; 4];
-                    vst1q_f32(temp.as_mut_ptr(), dot_product);
-                    result.data[i][j] = temp[j];
+                let mut acc = vdupq_n_f32(0.0);
+                for (&a, b) in row.iter().zip(other.data.iter()) {
+                    acc = vfmaq_n_f32(acc, vld1q_f32(b.as_ptr()), a);
                 }
+                vst1q_f32(out.as_mut_ptr(), acc);
+            }
+        }
+        result
+    }
+
+    /// AVX matrix product: two output rows share one 256-bit accumulator, halving
+    /// the multiply/add count of `mul_simd`. Callers must check AVX support.
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx")]
+    unsafe fn mul_avx(&self, other: &Mat4) -> Mat4 {
+        let other_rows = other.data.map(|row| {
+            let row = _mm_loadu_ps(row.as_ptr());
+            _mm256_set_m128(row, row)
+        });
+        let mut result = Mat4::new_zero();
+        let rows = self
+            .data
+            .chunks_exact(2)
+            .zip(result.data.chunks_exact_mut(2));
+        for (pair, out) in rows {
+            let mut acc = _mm256_setzero_ps();
+            for (k, other_row) in other_rows.iter().enumerate() {
+                let scale = _mm256_set_m128(_mm_set1_ps(pair[1][k]), _mm_set1_ps(pair[0][k]));
+                acc = _mm256_add_ps(acc, _mm256_mul_ps(scale, *other_row));
             }
+            _mm256_storeu_ps(out.as_mut_ptr().cast::<f32>(), acc);
         }
         result
     }
 
+    /// Matrix product through the fastest path this CPU supports. On x86_64 that is
+    /// AVX when available, otherwise SSE, which every x86_64 CPU has. With `std`, AVX
+    /// is detected at runtime on the first call and cached; under `no_std` the choice
+    /// is made at compile time from the enabled target features. aarch64 always has
+    /// NEON, and other targets use the scalar `mul`.
     pub fn mul_auto(&self, other: &Mat4) -> Mat4 {
         #[cfg(target_arch = "x86_64")]
         {
-            return self.mul_simd(other);
+            match x86_simd() {
+                X86Simd::Avx => unsafe { self.mul_avx(other) },
+                X86Simd::Sse => self.mul_simd(other),
+            }
         }
         #[cfg(target_arch = "aarch64")]
         {
             self.mul_neon(other)
         }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            Mat4::mul(self, other)
+        }
+    }
+
+    /// Fills `out[i] = lhs[i] * rhs[i]` using the SIMD path picked by `mul_auto`.
+    /// Panics if the slice lengths differ.
+    pub fn mul_batch(lhs: &[Mat4], rhs: &[Mat4], out: &mut [Mat4]) {
+        assert_eq!(lhs.len(), rhs.len());
+        assert_eq!(lhs.len(), out.len());
+        for ((a, b), result) in lhs.iter().zip(rhs).zip(out.iter_mut()) {
+            *result = a.mul_auto(b);
+        }
     }
 
+    /// True for a pure rotation: an orthonormal upper-left 3x3 with determinant +1
+    /// (no reflection), no translation and an identity bottom row.
+    pub fn is_rotation(&self, epsilon: f32) -> bool {
+        let near = |a: f32, b: f32| math::approx_equal(a, b, epsilon);
+        let block = self.to_mat3();
+        let gram = block.mul(&block.transpose());
+        let orthonormal = gram
+            .data
+            .iter()
+            .flatten()
+            .zip(Mat3::new_identity().data.iter().flatten())
+            .all(|(&a, &b)| near(a, b));
+        let affine = (0..3).all(|i| near(self.data[i][3], 0.0) && near(self.data[3][i], 0.0))
+            && near(self.data[3][3], 1.0);
+        orthonormal && affine && near(block.determinant(), 1.0)
+    }
+
+    /// Factor by which the transform scales volumes: the absolute determinant of
+    /// the upper-left 3x3 block.
+    pub fn volume_scale(&self) -> f32 {
+        self.to_mat3().determinant().abs()
+    }
+
+    /// Inverse via Gauss-Jordan elimination, or `None` if the matrix is singular or
+    /// contains non-finite values. Use `try_invert` to tell the two apart.
     pub fn invert(&self) -> Option<Mat4> {
-        let result = Mat4::new_identity();
-        let _ = self.clone();
+        self.try_invert().ok()
+    }
+
+    pub fn transpose(&self) -> Mat4 {
+        let mut result = Mat4::new_zero();
+        for i in 0..4 {
+            for j in 0..4 {
+                result.data[i][j] = self.data[j][i];
+            }
+        }
+        result
+    }
+
+    /// Laplace expansion over the 2x2 minors of the top and bottom row pairs.
+    pub fn determinant(&self) -> f32 {
+        let m = &self.data;
+        let s0 = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        let s1 = m[0][0] * m[1][2] - m[0][2] * m[1][0];
+        let s2 = m[0][0] * m[1][3] - m[0][3] * m[1][0];
+        let s3 = m[0][1] * m[1][2] - m[0][2] * m[1][1];
+        let s4 = m[0][1] * m[1][3] - m[0][3] * m[1][1];
+        let s5 = m[0][2] * m[1][3] - m[0][3] * m[1][2];
+
+        let c0 = m[2][0] * m[3][1] - m[2][1] * m[3][0];
+        let c1 = m[2][0] * m[3][2] - m[2][2] * m[3][0];
+        let c2 = m[2][0] * m[3][3] - m[2][3] * m[3][0];
+        let c3 = m[2][1] * m[3][2] - m[2][2] * m[3][1];
+        let c4 = m[2][1] * m[3][3] - m[2][3] * m[3][1];
+        let c5 = m[2][2] * m[3][3] - m[2][3] * m[3][2];
+
+        s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+    }
+
+    pub fn try_invert(&self) -> Result<Mat4, InvertError> {
+        if self.data.iter().flatten().any(|value| !value.is_finite()) {
+            return Err(InvertError::NonFinite);
+        }
+        // Pivots are judged relative to the largest entry so a uniformly scaled
+        // matrix inverts regardless of its magnitude.
+        let tolerance = EPSILON
+            * self
+                .data
+                .iter()
+                .flatten()
+                .fold(0.0f32, |acc, v| acc.max(v.abs()));
+        let mut m = *self;
+        let mut inverse = Mat4::new_identity();
+        for col in 0..4 {
+            let pivot = (col..4)
+                .max_by(|&a, &b| m.data[a][col].abs().total_cmp(&m.data[b][col].abs()))
+                .unwrap_or(col);
+            if m.data[pivot][col].abs() <= tolerance {
+                return Err(InvertError::Singular);
+            }
+            m.swap_rows(col, pivot);
+            inverse.swap_rows(col, pivot);
+
+            let scale = 1.0 / m.data[col][col];
+            m.scale_row(col, scale);
+            inverse.scale_row(col, scale);
+            for row in 0..4 {
+                if row == col {
+                    continue;
+                }
+                let factor = -m.data[row][col];
+                m.add_scaled_row(row, col, factor);
+                inverse.add_scaled_row(row, col, factor);
+            }
+        }
+        Ok(inverse)
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.data.swap(a, b);
+    }
+
+    pub fn scale_row(&mut self, i: usize, factor: f32) {
+        for value in self.data[i].iter_mut() {
+            *value *= factor;
+        }
+    }
+
+    /// Adds `factor` times row `src` to row `dst`.
+    pub fn add_scaled_row(&mut self, dst: usize, src: usize, factor: f32) {
+        let source = self.data[src];
+        for (value, source_value) in self.data[dst].iter_mut().zip(source.iter()) {
+            *value += factor * source_value;
+        }
+    }
+
+    pub fn rank(&self, epsilon: f32) -> usize {
+        let mut m = self.data;
+        let mut rank = 0;
+        for col in 0..4 {
+            let pivot = (rank..4)
+                .max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))
+                .unwrap_or(rank);
+            if m[pivot][col].abs() <= epsilon {
+                continue;
+            }
+            m.swap(rank, pivot);
+            let pivot_row = m[rank];
+            for row in m.iter_mut().skip(rank + 1) {
+                let factor = row[col] / pivot_row[col];
+                for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                    *value -= factor * pivot_value;
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+
+    /// Reads 16 values in row-major order; `None` if the iterator runs out early.
+    /// Extra values are left unconsumed.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_iter(iter: impl IntoIterator<Item = f32>) -> Option<Mat4> {
+        let mut values = iter.into_iter();
+        let mut result = Mat4::new_zero();
+        for value in result.data.iter_mut().flatten() {
+            *value = values.next()?;
+        }
         Some(result)
     }
 
+    pub fn from_rows(r0: Vec4, r1: Vec4, r2: Vec4, r3: Vec4) -> Mat4 {
+        Mat4 {
+            data: [r0, r1, r2, r3].map(|r| [r.x, r.y, r.z, r.w]),
+        }
+    }
+
+    /// Builds a matrix whose columns are `c0`..`c3`; `c3` holds the translation of
+    /// an affine transform.
+    pub fn from_cols(c0: Vec4, c1: Vec4, c2: Vec4, c3: Vec4) -> Mat4 {
+        Mat4::from_rows(c0, c1, c2, c3).transpose()
+    }
+
+    /// Affine transform with the given basis axes as its first three columns and
+    /// `translation` as the fourth.
+    pub fn from_cols3(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3, translation: Vec3) -> Mat4 {
+        let extend = |v: Vec3, w: f32| Vec4::new(v.x, v.y, v.z, w);
+        Mat4::from_cols(
+            extend(x_axis, 0.0),
+            extend(y_axis, 0.0),
+            extend(z_axis, 0.0),
+            extend(translation, 1.0),
+        )
+    }
+
+    pub fn row(&self, i: usize) -> Vec4 {
+        let [x, y, z, w] = self.data[i];
+        Vec4::new(x, y, z, w)
+    }
+
+    pub fn col(&self, j: usize) -> Vec4 {
+        Vec4::new(
+            self.data[0][j],
+            self.data[1][j],
+            self.data[2][j],
+            self.data[3][j],
+        )
+    }
+
+    /// Builds a matrix from 16 values laid out in `order`.
+    pub fn from_flat(data: [f32; 16], order: MajorOrder) -> Mat4 {
+        let mut result = Mat4::new_zero();
+        for (row, values) in result.data.iter_mut().enumerate() {
+            for (col, value) in values.iter_mut().enumerate() {
+                *value = data[flat_index(row, col, order)];
+            }
+        }
+        result
+    }
+
+    /// Writes the matrix as 16 values laid out in `order`.
+    pub fn to_flat(&self, order: MajorOrder) -> [f32; 16] {
+        let mut flat = [0.0; 16];
+        for (row, values) in self.data.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                flat[flat_index(row, col, order)] = value;
+            }
+        }
+        flat
+    }
+
+    /// Reads a glTF `matrix`: 16 floats in column-major order.
+    pub fn from_gltf(values: &[f32; 16]) -> Mat4 {
+        Mat4::from_flat(*values, MajorOrder::ColMajor)
+    }
+
+    /// Writes the matrix as a glTF `matrix`: 16 floats in column-major order.
+    pub fn to_gltf(&self) -> [f32; 16] {
+        self.to_flat(MajorOrder::ColMajor)
+    }
+
+    /// Applies this (view-)projection matrix to a world-space point and performs the
+    /// perspective divide, yielding normalized device coordinates. Returns `None` for
+    /// a point on the camera plane (`|w| <= EPSILON`), which has no NDC position.
+    pub fn world_to_ndc(&self, world: Vec3) -> Option<Vec3> {
+        let clip = self.mul_vec4(&Vec4::new(world.x, world.y, world.z, 1.0));
+        if clip.w.abs() <= EPSILON {
+            return None;
+        }
+        Some(Vec3::new(clip.x / clip.w, clip.y / clip.w, clip.z / clip.w))
+    }
+
+    /// Interpolates between two rigid transforms (rotation + translation, no scale):
+    /// translation is lerped and rotation follows the shortest arc between the two
+    /// orthonormal bases.
+    pub fn interpolate_rigid(&self, other: &Mat4, t: f32) -> Mat4 {
+        let mut relative = [[0.0; 3]; 3];
+        for (i, row) in relative.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.data[k][i] * other.data[k][j]).sum();
+            }
+        }
+        let mut result = match rotation_to_axis_angle(&relative) {
+            Some((axis, angle)) => self.mul(&Mat4::rotation_about_axis(&axis, angle * t)),
+            None => *self,
+        };
+        for i in 0..3 {
+            result.data[i][3] = math::lerp(self.data[i][3], other.data[i][3], t);
+        }
+        result
+    }
+
+    /// Normalizes the first three columns to unit length, leaving translation untouched.
+    pub fn remove_scale(&self) -> Mat4 {
+        let mut result = *self;
+        for col in 0..3 {
+            let len = Vec3::new(self.data[0][col], self.data[1][col], self.data[2][col]).length();
+            if len > 0.0 {
+                for row in 0..3 {
+                    result.data[row][col] /= len;
+                }
+            }
+        }
+        result
+    }
+
+    /// Closest orthogonal matrix to the upper-left 3x3 (translation dropped), found by
+    /// polar decomposition: repeatedly averaging the matrix with its inverse-transpose.
+    /// The sign of the determinant is preserved, so reflections stay reflections.
+    /// A singular (or non-finite) 3x3 has no orthogonal factor; the identity is
+    /// returned instead.
+    pub fn extract_rotation_matrix(&self) -> Mat4 {
+        // The orthogonal factor is unchanged by uniform scaling, so bring the block
+        // to the Frobenius norm of a rotation (sqrt 3) first. Otherwise small-scale
+        // transforms fall under the determinant threshold of the inverse.
+        let norm = self.data[..3]
+            .iter()
+            .flat_map(|row| &row[..3])
+            .map(|v| v * v)
+            .sum::<f32>()
+            .sqrt();
+        if !(norm > 0.0 && norm.is_finite()) {
+            return Mat4::new_identity();
+        }
+        let scale = 3.0f32.sqrt() / norm;
+        let mut m = [[0.0; 3]; 3];
+        for (row, source) in m.iter_mut().zip(self.data.iter()) {
+            for (value, &element) in row.iter_mut().zip(source.iter()) {
+                *value = element * scale;
+            }
+        }
+        for _ in 0..32 {
+            let Some(inverse_transpose) = inverse_transpose_3x3(&m) else {
+                return Mat4::new_identity();
+            };
+            let mut change = 0.0f32;
+            for i in 0..3 {
+                for j in 0..3 {
+                    let next = 0.5 * (m[i][j] + inverse_transpose[i][j]);
+                    change = change.max((next - m[i][j]).abs());
+                    m[i][j] = next;
+                }
+            }
+            if change < EPSILON * 0.1 {
+                break;
+            }
+        }
+        let mut result = Mat4::new_identity();
+        for (i, row) in m.iter().enumerate() {
+            result.data[i][..3].copy_from_slice(row);
+        }
+        result
+    }
+
+    /// Tight bounds of `aabb` after an affine transform, computed from the box center
+    /// and the absolute values of the linear part rather than all eight corners.
+    pub fn transform_aabb(&self, aabb: &Aabb) -> Aabb {
+        let center = aabb.center();
+        let extents = aabb.half_extents();
+        let mut new_center = [0.0; 3];
+        let mut new_extents = [0.0; 3];
+        for i in 0..3 {
+            let row = &self.data[i];
+            new_center[i] = row[0] * center.x + row[1] * center.y + row[2] * center.z + row[3];
+            new_extents[i] =
+                row[0].abs() * extents.x + row[1].abs() * extents.y + row[2].abs() * extents.z;
+        }
+        let center = Vec3::new(new_center[0], new_center[1], new_center[2]);
+        let extents = Vec3::new(new_extents[0], new_extents[1], new_extents[2]);
+        Aabb::new(center.sub(&extents), center.add(&extents))
+    }
+
+    fn rotation_about_axis(axis: &Vec3, angle: f32) -> Mat4 {
+        let (x, y, z) = (axis.x, axis.y, axis.z);
+        let cos_theta = angle.cos();
+        let sin_theta = angle.sin();
+        let t = 1.0 - cos_theta;
+        Mat4 {
+            data: [
+                [
+                    cos_theta + x * x * t,
+                    x * y * t - z * sin_theta,
+                    x * z * t + y * sin_theta,
+                    0.0,
+                ],
+                [
+                    x * y * t + z * sin_theta,
+                    cos_theta + y * y * t,
+                    y * z * t - x * sin_theta,
+                    0.0,
+                ],
+                [
+                    x * z * t - y * sin_theta,
+                    y * z * t + x * sin_theta,
+                    cos_theta + z * z * t,
+                    0.0,
+                ],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
     pub fn scale(sx: f32, sy: f32, sz: f32) -> Mat4 {
         Mat4 {
             data: [
@@ -124,6 +732,65 @@ impl Mat4 {
         }
     }
 
+    /// Shear in the xy plane: `x' = x + shx * y` and `y' = y + shy * x`. The z
+    /// axis is left untouched.
+    pub fn shear_2d(shx: f32, shy: f32) -> Mat4 {
+        Mat4 {
+            data: [
+                [1.0, shx, 0.0, 0.0],
+                [shy, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Rotation matrix of a unit quaternion. Prefer this over chaining
+    /// `rotate_x`/`rotate_y`/`rotate_z`, which accumulates rounding error.
+    pub fn rotation_from_quat(q: &Quat) -> Mat4 {
+        let (x, y, z, w) = (q.x, q.y, q.z, q.w);
+        Mat4 {
+            data: [
+                [
+                    1.0 - 2.0 * (y * y + z * z),
+                    2.0 * (x * y - w * z),
+                    2.0 * (x * z + w * y),
+                    0.0,
+                ],
+                [
+                    2.0 * (x * y + w * z),
+                    1.0 - 2.0 * (x * x + z * z),
+                    2.0 * (y * z - w * x),
+                    0.0,
+                ],
+                [
+                    2.0 * (x * z - w * y),
+                    2.0 * (y * z + w * x),
+                    1.0 - 2.0 * (x * x + y * y),
+                    0.0,
+                ],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Euler rotation equivalent to `rotate_x(x) * rotate_y(y) * rotate_z(z)`, so
+    /// `z` is applied first. Built through a quaternion.
+    pub fn from_euler(x: f32, y: f32, z: f32) -> Mat4 {
+        let qx = Quat::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), x);
+        let qy = Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), y);
+        let qz = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), z);
+        Mat4::rotation_from_quat(&qx.mul(&qy).mul(&qz))
+    }
+
+    /// `translate * from_euler(euler.x, euler.y, euler.z) * scale`: points are
+    /// scaled, then rotated (z, y, x), then translated.
+    pub fn trs_euler(translation: Vec3, euler: Vec3, scale: Vec3) -> Mat4 {
+        Mat4::translate(translation.x, translation.y, translation.z)
+            .mul(&Mat4::from_euler(euler.x, euler.y, euler.z))
+            .mul(&Mat4::scale(scale.x, scale.y, scale.z))
+    }
+
     pub fn rotate_x(angle: f32) -> Mat4 {
         let cos_theta = angle.cos();
         let sin_theta = angle.sin();
@@ -173,38 +840,581 @@ impl Mat4 {
             ],
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Scales by `scale` around `pivot`: `translate(pivot) * scale * translate(-pivot)`.
+    pub fn scale_about(pivot: Vec3, scale: Vec3) -> Mat4 {
+        Mat4::translate(pivot.x, pivot.y, pivot.z)
+            .mul(&Mat4::scale(scale.x, scale.y, scale.z))
+            .mul(&Mat4::translate(-pivot.x, -pivot.y, -pivot.z))
+    }
 
-    #[test]
-    fn test_new_identity() {
-        let identity = Mat4::new_identity();
-        let expected = Mat4 {
+    /// Rotates by `angle` radians about `axis` passing through `pivot`.
+    pub fn rotate_about(pivot: Vec3, axis: Vec3, angle: f32) -> Mat4 {
+        Mat4::translate(pivot.x, pivot.y, pivot.z)
+            .mul(&Mat4::rotation_about_axis(&axis.normalize(), angle))
+            .mul(&Mat4::translate(-pivot.x, -pivot.y, -pivot.z))
+    }
+
+    /// Right-handed perspective projection (camera looks down -z) mapping depth to
+    /// [-1, 1] as in OpenGL. An `aspect` of 0 is treated as 1. When `near == far`
+    /// the depth terms divide by zero and the matrix is not usable for projection.
+    pub fn perspective(fov_y_radians: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+        perspective_rh(fov_y_radians, aspect, near, far, DepthRange::NegOneToOne)
+    }
+
+    /// Left-handed counterpart of [`Mat4::perspective`]: the camera looks down +z,
+    /// so `near` and `far` are positive view-space z values. Depth still maps to
+    /// [-1, 1].
+    pub fn perspective_lh(fov_y_radians: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+        let mut result = Mat4::perspective(fov_y_radians, aspect, near, far);
+        for row in result.data.iter_mut() {
+            row[2] = -row[2];
+        }
+        result
+    }
+
+    /// OpenGL-style orthographic projection mapping the box to the [-1, 1] cube.
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Mat4 {
+        Mat4::orthographic_off_center(left, right, bottom, top, near, far, DepthRange::NegOneToOne)
+    }
+
+    /// Right-handed orthographic projection of the box [`left`, `right`] x
+    /// [`bottom`, `top`] x [-`near`, -`far`] (the camera looks down -z).
+    pub fn orthographic_off_center(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+        depth_range: DepthRange,
+    ) -> Mat4 {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+        let (z_scale, z_offset) = match depth_range {
+            DepthRange::NegOneToOne => (-2.0 / depth, -(far + near) / depth),
+            DepthRange::ZeroToOne => (-1.0 / depth, -near / depth),
+        };
+        Mat4 {
             data: [
-                [1.0, 0.0, 0.0, 0.0],
-                [0.0, 1.0, 0.0, 0.0],
-                [0.0, 0.0, 1.0, 0.0],
+                [2.0 / width, 0.0, 0.0, -(right + left) / width],
+                [0.0, 2.0 / height, 0.0, -(top + bottom) / height],
+                [0.0, 0.0, z_scale, z_offset],
                 [0.0, 0.0, 0.0, 1.0],
             ],
-        };
-        assert_eq!(identity, expected);
+        }
     }
 
-    #[test]
-    fn test_new_zero() {
-        let zero = Mat4::new_zero();
-        let expected = Mat4 {
-            data: [[0.0; 4]; 4],
-        };
-        assert_eq!(zero, expected);
+    /// Right-handed view matrix: the camera at `eye` looks down its local -z
+    /// towards `target`, with `up` roughly along its local +y.
+    pub fn look_at(eye: &Vec3, target: &Vec3, up: &Vec3) -> Mat4 {
+        let forward = target.sub(eye).normalize();
+        let right = forward.cross(up).normalize();
+        let up = right.cross(&forward);
+        Mat4 {
+            data: [
+                [right.x, right.y, right.z, -right.dot(eye)],
+                [up.x, up.y, up.z, -up.dot(eye)],
+                [-forward.x, -forward.y, -forward.z, forward.dot(eye)],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
     }
 
-    #[test]
-    fn test_add() {
-        let mat1 = Mat4 {
+    /// Left-handed counterpart of [`Mat4::look_at`]: same camera basis, but the
+    /// view-space z axis points towards `target` instead of away from it.
+    pub fn look_at_lh(eye: &Vec3, target: &Vec3, up: &Vec3) -> Mat4 {
+        let mut result = Mat4::look_at(eye, target, up);
+        for value in result.data[2].iter_mut() {
+            *value = -*value;
+        }
+        result
+    }
+
+    /// Builds a frame whose first three columns are `right`, `up` and `forward` and
+    /// whose translation column is `origin`.
+    pub fn from_basis(right: Vec3, up: Vec3, forward: Vec3, origin: Vec3) -> Mat4 {
+        Mat4 {
+            data: [
+                [right.x, up.x, forward.x, origin.x],
+                [right.y, up.y, forward.y, origin.y],
+                [right.z, up.z, forward.z, origin.z],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Maps NDC ([-1, 1] on every axis) to window coordinates: x/y into the
+    /// rectangle at (`x`, `y`) of size `width` x `height`, z into [`near`, `far`].
+    pub fn viewport(x: f32, y: f32, width: f32, height: f32, near: f32, far: f32) -> Mat4 {
+        let half_width = width * 0.5;
+        let half_height = height * 0.5;
+        let half_depth = (far - near) * 0.5;
+        Mat4 {
+            data: [
+                [half_width, 0.0, 0.0, x + half_width],
+                [0.0, half_height, 0.0, y + half_height],
+                [0.0, 0.0, half_depth, near + half_depth],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+}
+
+/// `m[(row, col)]`, panicking on out-of-bounds indices like array access.
+impl Index<(usize, usize)> for Mat4 {
+    type Output = f32;
+
+    fn index(&self, (row, col): (usize, usize)) -> &f32 {
+        &self.data[row][col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Mat4 {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f32 {
+        &mut self.data[row][col]
+    }
+}
+
+impl Add<&Mat4> for &Mat4 {
+    type Output = Mat4;
+
+    fn add(self, rhs: &Mat4) -> Mat4 {
+        Mat4::add(self, rhs)
+    }
+}
+
+impl Sub<&Mat4> for &Mat4 {
+    type Output = Mat4;
+
+    fn sub(self, rhs: &Mat4) -> Mat4 {
+        Mat4::sub(self, rhs)
+    }
+}
+
+impl Mul<&Mat4> for &Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: &Mat4) -> Mat4 {
+        Mat4::mul(self, rhs)
+    }
+}
+
+/// Forwards the owned/borrowed operand combinations of a `Mat4` operator to the
+/// `&Mat4 op &Mat4` impl. Taking `&Mat4` on the right for owned `self` also keeps
+/// calls like `Mat4::scale(..).mul(&other)` compiling.
+macro_rules! forward_mat4_binop {
+    ($trait:ident, $method:ident) => {
+        impl $trait<Mat4> for Mat4 {
+            type Output = Mat4;
+
+            fn $method(self, rhs: Mat4) -> Mat4 {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $trait<&Mat4> for Mat4 {
+            type Output = Mat4;
+
+            fn $method(self, rhs: &Mat4) -> Mat4 {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $trait<Mat4> for &Mat4 {
+            type Output = Mat4;
+
+            fn $method(self, rhs: Mat4) -> Mat4 {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+forward_mat4_binop!(Add, add);
+forward_mat4_binop!(Sub, sub);
+forward_mat4_binop!(Mul, mul);
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        self.mul_vec4(&rhs)
+    }
+}
+
+impl Mul<Vec4> for &Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Vec4 {
+        self.mul_vec4(&rhs)
+    }
+}
+
+impl Mat3 {
+    pub fn new_identity() -> Mat3 {
+        Mat3 {
+            data: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    pub fn new_zero() -> Mat3 {
+        Mat3 {
+            data: [[0.0; 3]; 3],
+        }
+    }
+
+    pub fn add(&self, other: &Mat3) -> Mat3 {
+        let mut result = Mat3::new_zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                result.data[i][j] = self.data[i][j] + other.data[i][j];
+            }
+        }
+        result
+    }
+
+    pub fn mul(&self, other: &Mat3) -> Mat3 {
+        let mut result = Mat3::new_zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    result.data[i][j] += self.data[i][k] * other.data[k][j];
+                }
+            }
+        }
+        result
+    }
+
+    pub fn mul_vec3(&self, v: &Vec3) -> Vec3 {
+        let row = |r: &[f32; 3]| r[0] * v.x + r[1] * v.y + r[2] * v.z;
+        Vec3::new(row(&self.data[0]), row(&self.data[1]), row(&self.data[2]))
+    }
+
+    pub fn transpose(&self) -> Mat3 {
+        let mut result = Mat3::new_zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                result.data[j][i] = self.data[i][j];
+            }
+        }
+        result
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let m = &self.data;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Inverse via the adjugate; `None` when the determinant is negligible
+    /// relative to the entries (below `EPSILON` times the largest entry cubed).
+    pub fn invert(&self) -> Option<Mat3> {
+        inverse_transpose_3x3(&self.data).map(|data| Mat3 { data }.transpose())
+    }
+
+    /// Upper-left 3x3 block of `m`, dropping the translation column and projective row.
+    pub fn from_mat4(m: &Mat4) -> Mat3 {
+        let mut data = [[0.0; 3]; 3];
+        for (row, source) in data.iter_mut().zip(m.data.iter()) {
+            row.copy_from_slice(&source[..3]);
+        }
+        Mat3 { data }
+    }
+}
+
+impl From<Mat4> for Mat3 {
+    fn from(m: Mat4) -> Mat3 {
+        Mat3::from_mat4(&m)
+    }
+}
+
+impl Mat2 {
+    pub fn new_identity() -> Mat2 {
+        Mat2 {
+            data: [[1.0, 0.0], [0.0, 1.0]],
+        }
+    }
+
+    pub fn new_zero() -> Mat2 {
+        Mat2 {
+            data: [[0.0; 2]; 2],
+        }
+    }
+
+    /// Counter-clockwise rotation by `angle` radians.
+    pub fn rotation(angle: f32) -> Mat2 {
+        let (sin, cos) = angle.sin_cos();
+        Mat2 {
+            data: [[cos, -sin], [sin, cos]],
+        }
+    }
+
+    pub fn mul(&self, other: &Mat2) -> Mat2 {
+        let [[a, b], [c, d]] = self.data;
+        let [[e, f], [g, h]] = other.data;
+        Mat2 {
+            data: [
+                [a * e + b * g, a * f + b * h],
+                [c * e + d * g, c * f + d * h],
+            ],
+        }
+    }
+
+    pub fn mul_vec2(&self, v: &Vec2) -> Vec2 {
+        let [[a, b], [c, d]] = self.data;
+        Vec2::new(a * v.x + b * v.y, c * v.x + d * v.y)
+    }
+
+    pub fn transpose(&self) -> Mat2 {
+        let [[a, b], [c, d]] = self.data;
+        Mat2 {
+            data: [[a, c], [b, d]],
+        }
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let [[a, b], [c, d]] = self.data;
+        a * d - b * c
+    }
+
+    /// Closed-form inverse; `None` when the determinant is below `EPSILON`.
+    pub fn invert(&self) -> Option<Mat2> {
+        let det = self.determinant();
+        if det.abs() < EPSILON {
+            return None;
+        }
+        let [[a, b], [c, d]] = self.data;
+        let inv_det = 1.0 / det;
+        Some(Mat2 {
+            data: [[d * inv_det, -b * inv_det], [-c * inv_det, a * inv_det]],
+        })
+    }
+}
+
+/// Widest x86_64 SIMD extension `mul_auto` can use. SSE is part of the x86_64
+/// baseline, so only AVX needs detecting.
+#[cfg(target_arch = "x86_64")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum X86Simd {
+    Sse = 1,
+    Avx = 2,
+}
+
+/// Runtime detection, cached after the first call so the hot path is a single
+/// relaxed load.
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+fn x86_simd() -> X86Simd {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    static STATE: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    match STATE.load(Ordering::Relaxed) {
+        UNKNOWN => {
+            let level = if std::arch::is_x86_feature_detected!("avx") {
+                X86Simd::Avx
+            } else {
+                X86Simd::Sse
+            };
+            STATE.store(level as u8, Ordering::Relaxed);
+            level
+        }
+        2 => X86Simd::Avx,
+        _ => X86Simd::Sse,
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+fn x86_simd() -> X86Simd {
+    if cfg!(target_feature = "avx") {
+        X86Simd::Avx
+    } else {
+        X86Simd::Sse
+    }
+}
+
+fn flat_index(row: usize, col: usize, order: MajorOrder) -> usize {
+    match order {
+        MajorOrder::RowMajor => row * 4 + col,
+        MajorOrder::ColMajor => col * 4 + row,
+    }
+}
+
+fn perspective_rh(
+    fov_y_radians: f32,
+    aspect: f32,
+    near: f32,
+    far: f32,
+    depth_range: DepthRange,
+) -> Mat4 {
+    let aspect = if aspect == 0.0 { 1.0 } else { aspect };
+    let focal = 1.0 / (fov_y_radians * 0.5).tan();
+    let (z_scale, z_offset) = match depth_range {
+        DepthRange::NegOneToOne => ((far + near) / (near - far), 2.0 * far * near / (near - far)),
+        DepthRange::ZeroToOne => (far / (near - far), far * near / (near - far)),
+    };
+    Mat4 {
+        data: [
+            [focal / aspect, 0.0, 0.0, 0.0],
+            [0.0, focal, 0.0, 0.0],
+            [0.0, 0.0, z_scale, z_offset],
+            [0.0, 0.0, -1.0, 0.0],
+        ],
+    }
+}
+
+/// Composes transforms in reading order: each call multiplies onto the right, so
+/// `TransformBuilder::new().translate(..).rotate_y(..).scale(..).build()` equals
+/// `translate * rotate_y * scale` and applies the scale to points first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformBuilder {
+    matrix: Mat4,
+}
+
+impl TransformBuilder {
+    pub fn new() -> TransformBuilder {
+        TransformBuilder {
+            matrix: Mat4::new_identity(),
+        }
+    }
+
+    pub fn then(self, transform: &Mat4) -> TransformBuilder {
+        TransformBuilder {
+            matrix: self.matrix.mul(transform),
+        }
+    }
+
+    pub fn translate(self, tx: f32, ty: f32, tz: f32) -> TransformBuilder {
+        self.then(&Mat4::translate(tx, ty, tz))
+    }
+
+    pub fn rotate_x(self, angle: f32) -> TransformBuilder {
+        self.then(&Mat4::rotate_x(angle))
+    }
+
+    pub fn rotate_y(self, angle: f32) -> TransformBuilder {
+        self.then(&Mat4::rotate_y(angle))
+    }
+
+    pub fn rotate_z(self, angle: f32) -> TransformBuilder {
+        self.then(&Mat4::rotate_z(angle))
+    }
+
+    pub fn scale(self, sx: f32, sy: f32, sz: f32) -> TransformBuilder {
+        self.then(&Mat4::scale(sx, sy, sz))
+    }
+
+    pub fn build(self) -> Mat4 {
+        self.matrix
+    }
+}
+
+impl Default for TransformBuilder {
+    fn default() -> TransformBuilder {
+        TransformBuilder::new()
+    }
+}
+
+fn inverse_transpose_3x3(m: &[[f32; 3]; 3]) -> Option<[[f32; 3]; 3]> {
+    // The cofactor matrix is det(m) times the inverse-transpose.
+    let cofactor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let cofactors = [
+        [
+            cofactor(1, 2, 1, 2),
+            -cofactor(1, 2, 0, 2),
+            cofactor(1, 2, 0, 1),
+        ],
+        [
+            -cofactor(0, 2, 1, 2),
+            cofactor(0, 2, 0, 2),
+            -cofactor(0, 2, 0, 1),
+        ],
+        [
+            cofactor(0, 1, 1, 2),
+            -cofactor(0, 1, 0, 2),
+            cofactor(0, 1, 0, 1),
+        ],
+    ];
+    let det = m[0][0] * cofactors[0][0] + m[0][1] * cofactors[0][1] + m[0][2] * cofactors[0][2];
+    // The determinant scales with the cube of the entries, so compare it against
+    // the largest entry cubed; an absolute threshold would reject small scales.
+    let max_abs = m.iter().flatten().fold(0.0f32, |acc, v| acc.max(v.abs()));
+    if det.abs() <= EPSILON * max_abs.powi(3) {
+        return None;
+    }
+    Some(cofactors.map(|row| row.map(|value| value / det)))
+}
+
+fn rotation_to_axis_angle(r: &[[f32; 3]; 3]) -> Option<(Vec3, f32)> {
+    let cos_angle = math::clamp((r[0][0] + r[1][1] + r[2][2] - 1.0) * 0.5, -1.0, 1.0);
+    let angle = cos_angle.acos();
+    if angle < EPSILON {
+        return None;
+    }
+    if math::PI - angle > 1e-3 {
+        let axis = Vec3::new(r[2][1] - r[1][2], r[0][2] - r[2][0], r[1][0] - r[0][1]);
+        return Some((axis.normalize(), angle));
+    }
+    // Near a half turn the antisymmetric part vanishes; read the axis from
+    // the symmetric part R = 2 * a * a^T - I instead.
+    let xx = ((r[0][0] + 1.0) * 0.5).max(0.0);
+    let yy = ((r[1][1] + 1.0) * 0.5).max(0.0);
+    let zz = ((r[2][2] + 1.0) * 0.5).max(0.0);
+    let xy = (r[0][1] + r[1][0]) * 0.25;
+    let xz = (r[0][2] + r[2][0]) * 0.25;
+    let yz = (r[1][2] + r[2][1]) * 0.25;
+    let axis = if xx >= yy && xx >= zz {
+        let x = xx.sqrt();
+        Vec3::new(x, xy / x, xz / x)
+    } else if yy >= zz {
+        let y = yy.sqrt();
+        Vec3::new(xy / y, y, yz / y)
+    } else {
+        let z = zz.sqrt();
+        Vec3::new(xz / z, yz / z, z)
+    };
+    Some((axis.normalize(), angle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transform(mat: &Mat4, v: [f32; 4]) -> [f32; 4] {
+        let result = mat.mul_vec4(&Vec4::new(v[0], v[1], v[2], v[3]));
+        [result.x, result.y, result.z, result.w]
+    }
+
+    #[test]
+    fn test_new_identity() {
+        let identity = Mat4::new_identity();
+        let expected = Mat4 {
+            data: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        };
+        assert_eq!(identity, expected);
+    }
+
+    #[test]
+    fn test_new_zero() {
+        let zero = Mat4::new_zero();
+        let expected = Mat4 {
+            data: [[0.0; 4]; 4],
+        };
+        assert_eq!(zero, expected);
+    }
+
+    #[test]
+    fn test_add() {
+        let mat1 = Mat4 {
             data: [
                 [1.0, 2.0, 3.0, 4.0],
                 [5.0, 6.0, 7.0, 8.0],
@@ -255,69 +1465,258 @@ mod tests {
     }
 
     #[test]
-    fn test_scale() {
-        let scale_mat = Mat4::scale(2.0, 3.0, 4.0);
-        let expected = Mat4 {
-            data: [
-                [2.0, 0.0, 0.0, 0.0],
-                [0.0, 3.0, 0.0, 0.0],
-                [0.0, 0.0, 4.0, 0.0],
-                [0.0, 0.0, 0.0, 1.0],
-            ],
-        };
-        assert_eq!(scale_mat, expected);
+    fn test_is_rotation() {
+        assert!(Mat4::new_identity().is_rotation(EPSILON));
+        assert!(Mat4::from_euler(0.3, -1.2, 2.5).is_rotation(EPSILON));
+        assert!(!Mat4::scale(1.0, 2.0, 1.0).is_rotation(EPSILON));
+        assert!(!Mat4::scale(-1.0, 1.0, 1.0).is_rotation(EPSILON));
+        assert!(!Mat4::translate(0.0, 1.0, 0.0).is_rotation(EPSILON));
     }
 
-    const EPSILON: f32 = 1e-5;
+    #[test]
+    fn test_volume_scale() {
+        assert_eq!(Mat4::scale(2.0, 2.0, 2.0).volume_scale(), 8.0);
+        assert!((Mat4::rotate_y(1.2).volume_scale() - 1.0).abs() < EPSILON);
+        assert_eq!(Mat4::scale(-1.0, 3.0, 1.0).volume_scale(), 3.0);
+        assert_eq!(Mat4::translate(5.0, 6.0, 7.0).volume_scale(), 1.0);
+    }
 
     #[test]
-    fn test_rotate_x() {
-        let angle = std::f32::consts::PI / 2.0;
-        let rotation_mat = Mat4::rotate_x(angle);
-        let expected = Mat4 {
-            data: [
-                [1.0, 0.0, 0.0, 0.0],
-                [0.0, 0.0, -1.0, 0.0],
-                [0.0, 1.0, 0.0, 0.0],
-                [0.0, 0.0, 0.0, 1.0],
-            ],
-        };
+    fn test_sub_and_mul_scalar() {
+        let mat = Mat4::rotate_x(0.9).mul(&Mat4::translate(1.0, -2.0, 3.0));
+        assert_eq!(Mat4::sub(&mat, &mat), Mat4::new_zero());
 
-        for i in 0..4 {
-            for j in 0..4 {
-                assert!((rotation_mat.data[i][j] - expected.data[i][j]).abs() < EPSILON);
+        let doubled = Mat4::new_identity().mul_scalar(2.0);
+        for (i, row) in doubled.data.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                assert_eq!(value, if i == j { 2.0 } else { 0.0 });
             }
         }
     }
 
     #[test]
-    fn test_rotate_y() {
-        let angle = std::f32::consts::PI / 2.0;
-        let rotation_mat = Mat4::rotate_y(angle);
-        let expected = Mat4 {
-            data: [
-                [0.0, 0.0, 1.0, 0.0],
-                [0.0, 1.0, 0.0, 0.0],
-                [-1.0, 0.0, 0.0, 0.0],
-                [0.0, 0.0, 0.0, 1.0],
-            ],
-        };
+    fn test_transform_slice() {
+        let translation = Mat4::translate(1.0, -2.0, 3.0);
+        let points = [
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+            Vec4::new(1.5, 2.5, -3.5, 1.0),
+            Vec4::new(-4.0, 8.0, 0.25, 1.0),
+            Vec4::new(1.0, 1.0, 1.0, 0.0),
+        ];
 
-        for i in 0..4 {
-            for j in 0..4 {
-                assert!((rotation_mat.data[i][j] - expected.data[i][j]).abs() < EPSILON);
-            }
+        let mut out = [Vec4::new(0.0, 0.0, 0.0, 0.0); 4];
+        translation.transform_slice_into(&points, &mut out);
+        let mut in_place = points;
+        translation.transform_slice(&mut in_place);
+
+        for ((point, result), moved) in points.iter().zip(out.iter()).zip(in_place.iter()) {
+            let expected = Vec4::new(
+                point.x + point.w,
+                point.y - 2.0 * point.w,
+                point.z + 3.0 * point.w,
+                point.w,
+            );
+            assert_eq!(*result, expected);
+            assert_eq!(*moved, expected);
+        }
+
+        let (a, _) = scrambled_pair();
+        let mut transformed = points;
+        a.transform_slice(&mut transformed);
+        for (point, result) in points.iter().zip(transformed.iter()) {
+            let expected = a.mul_vec4(point);
+            assert!(result.sub(&expected).length() < EPSILON);
         }
     }
 
     #[test]
-    fn test_rotate_z() {
-        let angle = std::f32::consts::PI / 2.0;
-        let rotation_mat = Mat4::rotate_z(angle);
-        let expected = Mat4 {
-            data: [
-                [0.0, -1.0, 0.0, 0.0],
-                [1.0, 0.0, 0.0, 0.0],
+    fn test_powi() {
+        let step = Mat4::rotate_z(std::f32::consts::FRAC_PI_4);
+        assert_mat4_near(&step.powi(8), &Mat4::new_identity());
+        assert_mat4_near(
+            &step.powi(3),
+            &Mat4::rotate_z(3.0 * std::f32::consts::FRAC_PI_4),
+        );
+        assert_eq!(step.powi(0), Mat4::new_identity());
+        assert_eq!(step.powi(1), step);
+
+        let shift = Mat4::translate(1.0, 0.0, 0.0).powi(1000);
+        assert_eq!(shift, Mat4::translate(1000.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_mul_transpose() {
+        let (a, b) = scrambled_pair();
+        assert_mat4_near(&a.mul_transpose(&b), &a.mul(&b.transpose()));
+        assert_mat4_near(&b.mul_transpose(&a), &b.mul(&a.transpose()));
+    }
+
+    #[test]
+    fn test_index() {
+        let mut mat = Mat4::new_identity();
+        mat[(0, 3)] = 5.0;
+        assert_eq!(mat[(0, 3)], 5.0);
+        assert_eq!(mat.data[0][3], 5.0);
+        assert_eq!(mat[(2, 2)], 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let mat = Mat4::new_identity();
+        let _ = mat[(0, 4)];
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = Mat4::rotate_z(0.5).mul(&Mat4::translate(1.0, 2.0, 3.0));
+        let b = Mat4::scale(2.0, 3.0, 4.0);
+        let (ra, rb) = (&a, &b);
+
+        assert_eq!(a * b, Mat4::mul(&a, &b));
+        assert_eq!(ra * rb, Mat4::mul(&a, &b));
+        assert_eq!(a * rb, ra * b);
+        assert_eq!(a + b, Mat4::add(&a, &b));
+        assert_eq!(ra + rb, Mat4::add(&a, &b));
+        assert_eq!(a - a, Mat4::new_zero());
+        let offset = Mat4::translate(3.0, 2.0, 1.0) - Mat4::new_identity();
+        assert_eq!(offset.data[0], [0.0, 0.0, 0.0, 3.0]);
+        assert_eq!(offset.data[3], [0.0; 4]);
+        assert_eq!(ra - b, a - rb);
+
+        let v = Vec4::new(1.0, -1.0, 0.5, 1.0);
+        assert_eq!(a * v, a.mul_vec4(&v));
+        assert_eq!(rb * v, Vec4::new(2.0, -3.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn test_is_identity() {
+        assert!(Mat4::new_identity().is_identity());
+        assert!(!Mat4::translate(0.0, 0.0, 1e-7).is_identity());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_debug_table() {
+        let table = Mat4::translate(1.5, -2.0, 3.25).debug_table();
+        assert_eq!(table.lines().count(), 8);
+        assert!(table.contains("1.5000"));
+        assert!(table.contains("-2.0000"));
+        assert!(table.contains("3.2500"));
+        assert!(table.lines().nth(1).unwrap().contains("c3"));
+        assert!(table.lines().nth(3).unwrap().starts_with("| r0 |"));
+        assert!(table
+            .lines()
+            .all(|line| line.len() == table.lines().next().unwrap().len()));
+    }
+
+    #[test]
+    fn test_bit_hash() {
+        let a = Mat4::rotate_x(0.4).mul(&Mat4::translate(1.0, 2.0, 3.0));
+        let b = Mat4::rotate_x(0.4).mul(&Mat4::translate(1.0, 2.0, 3.0));
+        assert_eq!(a.bit_hash(), b.bit_hash());
+
+        let mut perturbed = a;
+        perturbed.data[2][3] = f32::from_bits(perturbed.data[2][3].to_bits() + 1);
+        assert_ne!(a.bit_hash(), perturbed.bit_hash());
+
+        let mut negative_zero = Mat4::new_identity();
+        negative_zero.data[0][1] = -0.0;
+        assert_eq!(negative_zero, Mat4::new_identity());
+        assert_eq!(negative_zero.bit_hash(), Mat4::new_identity().bit_hash());
+    }
+
+    #[test]
+    fn test_mul_identity_fast_path() {
+        // A full multiply would turn inf * 0 into NaN; the fast path returns the
+        // operand untouched.
+        let mut mat = Mat4::rotate_y(0.3);
+        mat.data[0][0] = f32::INFINITY;
+        let identity = Mat4::new_identity();
+        for result in [mat.mul(&identity), identity.mul(&mat)] {
+            assert_eq!(result.data[0][0], f32::INFINITY);
+            assert_eq!(result.data[1..], mat.data[1..]);
+            assert_eq!(result.data[0][1..], mat.data[0][1..]);
+        }
+    }
+
+    #[test]
+    fn test_shear_2d() {
+        let shear = Mat4::shear_2d(0.5, 0.0);
+        assert_eq!(
+            transform(&shear, [1.0, 2.0, 3.0, 1.0]),
+            [2.0, 2.0, 3.0, 1.0]
+        );
+
+        let shear = Mat4::shear_2d(0.0, -1.0);
+        assert_eq!(
+            transform(&shear, [2.0, 1.0, 0.0, 1.0]),
+            [2.0, -1.0, 0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        let scale_mat = Mat4::scale(2.0, 3.0, 4.0);
+        let expected = Mat4 {
+            data: [
+                [2.0, 0.0, 0.0, 0.0],
+                [0.0, 3.0, 0.0, 0.0],
+                [0.0, 0.0, 4.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        };
+        assert_eq!(scale_mat, expected);
+    }
+
+    #[test]
+    fn test_rotate_x() {
+        let angle = std::f32::consts::PI / 2.0;
+        let rotation_mat = Mat4::rotate_x(angle);
+        let expected = Mat4 {
+            data: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, -1.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        };
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((rotation_mat.data[i][j] - expected.data[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_y() {
+        let angle = std::f32::consts::PI / 2.0;
+        let rotation_mat = Mat4::rotate_y(angle);
+        let expected = Mat4 {
+            data: [
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [-1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        };
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((rotation_mat.data[i][j] - expected.data[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_z() {
+        let angle = std::f32::consts::PI / 2.0;
+        let rotation_mat = Mat4::rotate_z(angle);
+        let expected = Mat4 {
+            data: [
+                [0.0, -1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0, 0.0],
                 [0.0, 0.0, 1.0, 0.0],
                 [0.0, 0.0, 0.0, 1.0],
             ],
@@ -344,6 +1743,217 @@ mod tests {
         assert_eq!(translation_mat, expected);
     }
 
+    #[test]
+    fn test_scale_about() {
+        let mat = Mat4::scale_about(Vec3::new(1.0, 2.0, 3.0), Vec3::new(2.0, 3.0, 4.0));
+        assert_eq!(transform(&mat, [1.0, 2.0, 3.0, 1.0]), [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(transform(&mat, [2.0, 3.0, 4.0, 1.0]), [3.0, 5.0, 7.0, 1.0]);
+    }
+
+    #[test]
+    fn test_rotate_about() {
+        let pivot = Vec3::new(1.0, 1.0, 0.0);
+        let mat = Mat4::rotate_about(pivot, Vec3::new(0.0, 0.0, 2.0), std::f32::consts::PI / 2.0);
+
+        let fixed = transform(&mat, [1.0, 1.0, 0.0, 1.0]);
+        let orbited = transform(&mat, [2.0, 1.0, 5.0, 1.0]);
+        let expected_fixed = [1.0, 1.0, 0.0, 1.0];
+        let expected_orbited = [1.0, 2.0, 5.0, 1.0];
+        for i in 0..4 {
+            assert!((fixed[i] - expected_fixed[i]).abs() < EPSILON);
+            assert!((orbited[i] - expected_orbited[i]).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_perspective() {
+        let projection = Mat4::perspective(std::f32::consts::PI / 2.0, 2.0, 1.0, 10.0);
+        let near = projection.world_to_ndc(Vec3::new(0.0, 0.0, -1.0)).unwrap();
+        let far = projection.world_to_ndc(Vec3::new(0.0, 0.0, -10.0)).unwrap();
+        assert!((near.z + 1.0).abs() < EPSILON);
+        assert!((far.z - 1.0).abs() < EPSILON);
+
+        let corner = projection.world_to_ndc(Vec3::new(2.0, 1.0, -1.0)).unwrap();
+        assert!((corner.x - 1.0).abs() < EPSILON);
+        assert!((corner.y - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_perspective_zero_aspect() {
+        let fov = std::f32::consts::PI / 3.0;
+        assert_eq!(
+            Mat4::perspective(fov, 0.0, 0.1, 100.0),
+            Mat4::perspective(fov, 1.0, 0.1, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_orthographic() {
+        let projection = Mat4::orthographic(-4.0, 2.0, 1.0, 5.0, 0.5, 10.5);
+
+        let center = projection.world_to_ndc(Vec3::new(-1.0, 3.0, -5.5)).unwrap();
+        assert!(center.length() < EPSILON);
+
+        let low = projection.world_to_ndc(Vec3::new(-4.0, 1.0, -0.5)).unwrap();
+        let high = projection.world_to_ndc(Vec3::new(2.0, 5.0, -10.5)).unwrap();
+        assert!(low.sub(&Vec3::new(-1.0, -1.0, -1.0)).length() < EPSILON);
+        assert!(high.sub(&Vec3::new(1.0, 1.0, 1.0)).length() < EPSILON);
+    }
+
+    #[test]
+    fn test_orthographic_off_center_depth_range() {
+        let gl =
+            Mat4::orthographic_off_center(-2.0, 4.0, -1.0, 3.0, 1.0, 11.0, DepthRange::NegOneToOne);
+        let dx =
+            Mat4::orthographic_off_center(-2.0, 4.0, -1.0, 3.0, 1.0, 11.0, DepthRange::ZeroToOne);
+
+        let near = [-2.0, -1.0, -1.0, 1.0];
+        let far = [4.0, 3.0, -11.0, 1.0];
+        assert_eq!(transform(&gl, near), [-1.0, -1.0, -1.0, 1.0]);
+        assert_eq!(transform(&gl, far), [1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(transform(&dx, near), [-1.0, -1.0, 0.0, 1.0]);
+        assert_eq!(transform(&dx, far), [1.0, 1.0, 1.0, 1.0]);
+
+        let middle = transform(&dx, [1.0, 1.0, -6.0, 1.0]);
+        assert!((middle[2] - 0.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_rotation_from_quat() {
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.7);
+        let rotation = Mat4::rotation_from_quat(&q);
+        let expected = Mat4::rotate_y(0.7);
+        for (row, expected_row) in rotation.data.iter().zip(expected.data.iter()) {
+            for (value, expected) in row.iter().zip(expected_row.iter()) {
+                assert!((value - expected).abs() < EPSILON);
+            }
+        }
+        assert_eq!(
+            Mat4::rotation_from_quat(&Quat::identity()),
+            Mat4::new_identity()
+        );
+    }
+
+    #[test]
+    fn test_from_euler_matches_axis_composition() {
+        for &(x, y, z) in &[(0.3, -1.1, 2.0), (1.5, 0.0, -0.4), (-2.8, 0.9, 0.1)] {
+            let euler = Mat4::from_euler(x, y, z);
+            let composed = Mat4::rotate_x(x)
+                .mul(&Mat4::rotate_y(y))
+                .mul(&Mat4::rotate_z(z));
+            for (row, expected_row) in euler.data.iter().zip(composed.data.iter()) {
+                for (value, expected) in row.iter().zip(expected_row.iter()) {
+                    assert!((value - expected).abs() < EPSILON);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_trs_euler() {
+        let translation = Vec3::new(1.0, -2.0, 3.5);
+        let scale = Vec3::new(2.0, 0.5, 3.0);
+        let mat = Mat4::trs_euler(translation, Vec3::new(0.4, -1.2, 2.2), scale);
+
+        let t = mat.col(3);
+        assert_eq!(Vec3::new(t.x, t.y, t.z), translation);
+        for (j, expected) in [scale.x, scale.y, scale.z].into_iter().enumerate() {
+            let axis = mat.col(j);
+            assert!((Vec3::new(axis.x, axis.y, axis.z).length() - expected).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_look_at() {
+        let eye = Vec3::new(0.0, 0.0, 5.0);
+        let view = Mat4::look_at(&eye, &Vec3::new(0.0, 0.0, 0.0), &Vec3::new(0.0, 1.0, 0.0));
+
+        assert_eq!(
+            transform(&view, [0.0, 0.0, 0.0, 1.0]),
+            [0.0, 0.0, -5.0, 1.0]
+        );
+        assert_eq!(transform(&view, [0.0, 0.0, 5.0, 1.0]), [0.0, 0.0, 0.0, 1.0]);
+
+        let forward = Vec3::new(view.data[2][0], view.data[2][1], view.data[2][2]);
+        assert!((forward.length() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_look_at_lh() {
+        let eye = Vec3::new(1.0, 2.0, 5.0);
+        let target = Vec3::new(-1.0, 0.5, 0.0);
+        let up = Vec3::new(0.0, 1.0, 0.0);
+        let rh = Mat4::look_at(&eye, &target, &up);
+        let lh = Mat4::look_at_lh(&eye, &target, &up);
+
+        assert_eq!(lh.data[0], rh.data[0]);
+        assert_eq!(lh.data[1], rh.data[1]);
+        assert_eq!(lh.data[2], rh.data[2].map(|v| -v));
+        assert_eq!(lh.data[3], rh.data[3]);
+
+        let distance = target.sub(&eye).length();
+        let in_view = transform(&lh, [target.x, target.y, target.z, 1.0]);
+        assert!((in_view[2] - distance).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_perspective_lh() {
+        let fov = std::f32::consts::FRAC_PI_3;
+        let rh = Mat4::perspective(fov, 1.5, 0.5, 50.0);
+        let lh = Mat4::perspective_lh(fov, 1.5, 0.5, 50.0);
+        for (lh_row, rh_row) in lh.data.iter().zip(rh.data.iter()) {
+            assert_eq!(lh_row[2], -rh_row[2]);
+            assert_eq!(
+                [lh_row[0], lh_row[1], lh_row[3]],
+                [rh_row[0], rh_row[1], rh_row[3]]
+            );
+        }
+
+        let near = lh.world_to_ndc(Vec3::new(0.0, 0.0, 0.5)).unwrap();
+        let far = lh.world_to_ndc(Vec3::new(0.0, 0.0, 50.0)).unwrap();
+        assert!((near.z + 1.0).abs() < EPSILON);
+        assert!((far.z - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_from_basis() {
+        let right = Vec3::new(0.0, 0.0, -1.0);
+        let up = Vec3::new(0.0, 1.0, 0.0);
+        let forward = Vec3::new(1.0, 0.0, 0.0);
+        let origin = Vec3::new(5.0, 6.0, 7.0);
+        let frame = Mat4::from_basis(right, up, forward, origin);
+
+        assert_eq!(
+            transform(&frame, [1.0, 0.0, 0.0, 1.0]),
+            [5.0, 6.0, 6.0, 1.0]
+        );
+        assert_eq!(
+            transform(&frame, [1.0, 0.0, 0.0, 0.0]),
+            [right.x, right.y, right.z, 0.0]
+        );
+        assert_eq!(
+            transform(&frame, [0.0, 0.0, 1.0, 0.0]),
+            [forward.x, forward.y, forward.z, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_viewport() {
+        let viewport = Mat4::viewport(10.0, 20.0, 800.0, 600.0, 0.0, 1.0);
+        assert_eq!(
+            transform(&viewport, [-1.0, -1.0, -1.0, 1.0]),
+            [10.0, 20.0, 0.0, 1.0]
+        );
+        assert_eq!(
+            transform(&viewport, [1.0, 1.0, 1.0, 1.0]),
+            [810.0, 620.0, 1.0, 1.0]
+        );
+        assert_eq!(
+            transform(&viewport, [0.0, 0.0, 0.0, 1.0]),
+            [410.0, 320.0, 0.5, 1.0]
+        );
+    }
+
     #[test]
     fn test_invert() {
         let mat = Mat4::new_identity();
@@ -351,6 +1961,663 @@ mod tests {
         assert_eq!(inverted, Some(mat));
     }
 
+    #[test]
+    fn test_transpose() {
+        let mat = Mat4 {
+            data: [
+                [1.0, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0],
+                [9.0, 10.0, 11.0, 12.0],
+                [13.0, 14.0, 15.0, 16.0],
+            ],
+        };
+        assert_eq!(mat.transpose().transpose(), mat);
+        assert_eq!(mat.transpose().data[0], [1.0, 5.0, 9.0, 13.0]);
+    }
+
+    #[test]
+    fn test_transpose_translate() {
+        let transposed = Mat4::translate(1.0, 2.0, 3.0).transpose();
+        assert_eq!(transposed.data[3], [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(transposed.data[0], [1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_determinant_identity() {
+        assert_eq!(Mat4::new_identity().determinant(), 1.0);
+    }
+
+    #[test]
+    fn test_determinant_scale() {
+        assert_eq!(Mat4::scale(2.0, 3.0, 4.0).determinant(), 24.0);
+    }
+
+    #[test]
+    fn test_determinant_known() {
+        let mat = Mat4 {
+            data: [
+                [1.0, 0.0, 2.0, -1.0],
+                [3.0, 0.0, 0.0, 5.0],
+                [2.0, 1.0, 4.0, -3.0],
+                [1.0, 0.0, 5.0, 0.0],
+            ],
+        };
+        assert_eq!(mat.determinant(), 30.0);
+
+        let mat = Mat4 {
+            data: [
+                [2.0, -1.0, 0.0, 3.0],
+                [1.0, 4.0, 2.0, -2.0],
+                [0.0, 3.0, 1.0, 5.0],
+                [-1.0, 2.0, -3.0, 1.0],
+            ],
+        };
+        assert_eq!(mat.determinant(), 231.0);
+        assert!((Mat4::rotate_x(0.7).determinant() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_try_invert() {
+        let mat = Mat4::translate(1.0, 2.0, 3.0).mul(&Mat4::scale(2.0, 4.0, 0.5));
+        let inverted = mat.try_invert().unwrap();
+        let product = mat.mul(&inverted);
+        let identity = Mat4::new_identity();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((product.data[i][j] - identity.data[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_invert_small_uniform_scale() {
+        let tiny = Mat4::new_identity().mul_scalar(1e-7);
+        let inverted = tiny.try_invert().unwrap();
+        assert_mat4_near(&inverted.mul_scalar(1e-7), &Mat4::new_identity());
+
+        let transform = Mat4::translate(1.0, 2.0, 3.0).mul(&Mat4::rotate_y(0.4));
+        let scaled = transform.mul_scalar(1e-6);
+        let inverted = scaled.invert().unwrap();
+        assert_mat4_near(&scaled.mul(&inverted), &Mat4::new_identity());
+        assert_eq!(Mat4::new_zero().try_invert(), Err(InvertError::Singular));
+    }
+
+    #[test]
+    fn test_try_invert_singular() {
+        let mut mat = Mat4::new_identity();
+        mat.data[2] = [0.0; 4];
+        assert_eq!(mat.try_invert(), Err(InvertError::Singular));
+    }
+
+    #[test]
+    fn test_try_invert_non_finite() {
+        let mut mat = Mat4::new_identity();
+        mat.data[1][3] = f32::NAN;
+        assert_eq!(mat.try_invert(), Err(InvertError::NonFinite));
+        mat.data[1][3] = f32::INFINITY;
+        assert_eq!(mat.try_invert(), Err(InvertError::NonFinite));
+    }
+
+    #[test]
+    fn test_interpolate_rigid() {
+        let start = Mat4::translate(0.0, 0.0, 0.0);
+        let end = Mat4::translate(2.0, 4.0, 6.0).mul(&Mat4::rotate_z(std::f32::consts::PI / 2.0));
+        let halfway = start.interpolate_rigid(&end, 0.5);
+        let expected =
+            Mat4::translate(1.0, 2.0, 3.0).mul(&Mat4::rotate_z(std::f32::consts::PI / 4.0));
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((halfway.data[i][j] - expected.data[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_rigid_stays_rotation() {
+        let a = Mat4::rotate_x(0.3).mul(&Mat4::rotate_y(-1.1));
+        let b = Mat4::rotate_z(2.0).mul(&Mat4::rotate_x(1.4));
+        for step in 0..=10 {
+            let m = a.interpolate_rigid(&b, step as f32 / 10.0);
+            for i in 0..3 {
+                for j in 0..3 {
+                    let dot: f32 = (0..3).map(|k| m.data[k][i] * m.data[k][j]).sum();
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    assert!((dot - expected).abs() < 1e-4);
+                }
+            }
+        }
+        let end = a.interpolate_rigid(&b, 1.0);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((end.data[i][j] - b.data[i][j]).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_rigid_half_turn() {
+        let a = Mat4::new_identity();
+        let b = Mat4::rotate_y(std::f32::consts::PI);
+        let halfway = a.interpolate_rigid(&b, 0.5);
+        let expected = Mat4::rotate_y(std::f32::consts::PI / 2.0);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((halfway.data[i][j] - expected.data[i][j]).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut mat = Mat4::new_identity();
+        mat.swap_rows(0, 2);
+        assert_eq!(mat.data[0], [0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(mat.data[2], [1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(mat.data[1], [0.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_scale_row() {
+        let mut mat = Mat4::new_identity();
+        mat.scale_row(1, 3.0);
+        assert_eq!(mat, Mat4::scale(1.0, 3.0, 1.0));
+    }
+
+    #[test]
+    fn test_add_scaled_row() {
+        let mut mat = Mat4::new_identity();
+        mat.add_scaled_row(0, 3, 2.0);
+        assert_eq!(mat.data[0], [1.0, 0.0, 0.0, 2.0]);
+        assert_eq!(mat.data[3], [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_remove_scale() {
+        let rotation = Mat4::rotate_y(0.7);
+        let mat = Mat4::translate(1.0, 2.0, 3.0)
+            .mul(&rotation)
+            .mul(&Mat4::scale(2.0, 3.0, 0.5));
+        let result = mat.remove_scale();
+        let expected = Mat4::translate(1.0, 2.0, 3.0).mul(&rotation);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((result.data[i][j] - expected.data[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_rotation_matrix() {
+        let rotation = Mat4::rotate_x(0.4).mul(&Mat4::rotate_z(-1.2));
+        let mut shear = Mat4::new_identity();
+        shear.data[0][1] = 0.05;
+        shear.data[2][0] = -0.03;
+        let drifted = Mat4::translate(4.0, 5.0, 6.0).mul(&rotation).mul(&shear);
+        let result = drifted.extract_rotation_matrix();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let dot: f32 = (0..3).map(|k| result.data[k][i] * result.data[k][j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-4);
+                assert!((result.data[i][j] - rotation.data[i][j]).abs() < 0.05);
+            }
+            assert_eq!(result.data[i][3], 0.0);
+        }
+    }
+
+    #[test]
+    fn test_extract_rotation_matrix_pure_rotation() {
+        let rotation = Mat4::rotate_y(0.9);
+        let result = rotation.extract_rotation_matrix();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((result.data[i][j] - rotation.data[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_rotation_matrix_small_scale() {
+        let rotation = Mat4::rotate_x(0.7).mul(&Mat4::rotate_y(-0.3));
+        let tiny = rotation.mul(&Mat4::scale(0.01, 0.01, 0.01));
+        assert_mat4_near(&tiny.extract_rotation_matrix(), &rotation);
+
+        let mut shear = Mat4::new_identity();
+        shear.data[0][1] = 0.05;
+        let tiny_sheared = rotation.mul(&shear).mul(&Mat4::scale(0.002, 0.002, 0.002));
+        assert!(tiny_sheared.extract_rotation_matrix().is_rotation(1e-4));
+    }
+
+    #[test]
+    fn test_extract_rotation_matrix_singular() {
+        let flat = Mat4::scale(1.0, 1.0, 0.0);
+        assert_eq!(flat.extract_rotation_matrix(), Mat4::new_identity());
+        assert_eq!(
+            Mat4::new_zero().extract_rotation_matrix(),
+            Mat4::new_identity()
+        );
+    }
+
+    #[test]
+    fn test_transform_aabb_translate() {
+        let aabb = Aabb::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 3.0));
+        let moved = Mat4::translate(1.0, -1.0, 2.0).transform_aabb(&aabb);
+        assert_eq!(moved.min, Vec3::new(1.0, -1.0, 2.0));
+        assert_eq!(moved.max, Vec3::new(2.0, 1.0, 5.0));
+    }
+
+    #[test]
+    fn test_transform_aabb_rotate() {
+        let cube = Aabb::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+        let rotated = Mat4::rotate_z(std::f32::consts::PI / 4.0).transform_aabb(&cube);
+        let sqrt_2 = std::f32::consts::SQRT_2;
+        assert!((rotated.max.x - sqrt_2).abs() < EPSILON);
+        assert!((rotated.max.y - sqrt_2).abs() < EPSILON);
+        assert!((rotated.min.x + sqrt_2).abs() < EPSILON);
+        assert!((rotated.max.z - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_rank_identity() {
+        assert_eq!(Mat4::new_identity().rank(EPSILON), 4);
+    }
+
+    #[test]
+    fn test_rank_zero_row() {
+        let mat = Mat4 {
+            data: [
+                [1.0, 2.0, 3.0, 4.0],
+                [0.0, 1.0, 5.0, 6.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [2.0, 1.0, 0.0, 1.0],
+            ],
+        };
+        assert_eq!(mat.rank(EPSILON), 3);
+        assert_eq!(Mat4::scale(1.0, 1.0, 0.0).rank(EPSILON), 3);
+        assert_eq!(Mat4::new_zero().rank(EPSILON), 0);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let values = (0..16).map(|i| if i % 5 == 0 { 1.0 } else { 0.0 });
+        assert_eq!(Mat4::from_iter(values), Some(Mat4::new_identity()));
+
+        let text = "1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16";
+        let parsed = Mat4::from_iter(text.split(' ').map(|v| v.parse().unwrap())).unwrap();
+        assert_eq!(parsed.data[1], [5.0, 6.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn test_from_iter_too_short() {
+        assert_eq!(Mat4::from_iter([1.0; 15]), None);
+    }
+
+    #[test]
+    fn test_from_gltf() {
+        // glTF node with scale 2 and translation (10, 20, 30), column-major.
+        let node = [
+            2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 10.0, 20.0, 30.0, 1.0,
+        ];
+        let mat = Mat4::from_gltf(&node);
+        assert_eq!(
+            mat,
+            Mat4::translate(10.0, 20.0, 30.0).mul(&Mat4::scale(2.0, 2.0, 2.0))
+        );
+        assert_eq!(
+            transform(&mat, [1.0, 1.0, 1.0, 1.0]),
+            [12.0, 22.0, 32.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_gltf_round_trip() {
+        let values: [f32; 16] = core::array::from_fn(|i| i as f32);
+        let mat = Mat4::from_gltf(&values);
+        assert_eq!(mat.data[1][0], 1.0);
+        assert_eq!(mat.data[0][1], 4.0);
+        assert_eq!(mat.to_gltf(), values);
+    }
+
+    #[test]
+    fn test_from_rows_round_trip() {
+        let rows = [
+            Vec4::new(1.0, 2.0, 3.0, 4.0),
+            Vec4::new(5.0, 6.0, 7.0, 8.0),
+            Vec4::new(9.0, 10.0, 11.0, 12.0),
+            Vec4::new(13.0, 14.0, 15.0, 16.0),
+        ];
+        let mat = Mat4::from_rows(rows[0], rows[1], rows[2], rows[3]);
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(mat.row(i), *row);
+        }
+        assert_eq!(mat.col(3), Vec4::new(4.0, 8.0, 12.0, 16.0));
+    }
+
+    #[test]
+    fn test_from_cols_round_trip() {
+        let cols = [
+            Vec4::new(1.0, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 2.0, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 3.0, 0.0),
+            Vec4::new(4.0, 5.0, 6.0, 1.0),
+        ];
+        let mat = Mat4::from_cols(cols[0], cols[1], cols[2], cols[3]);
+        for (j, col) in cols.iter().enumerate() {
+            assert_eq!(mat.col(j), *col);
+        }
+        assert_eq!(
+            mat,
+            Mat4::translate(4.0, 5.0, 6.0).mul(&Mat4::scale(1.0, 2.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn test_from_cols3() {
+        let identity = Mat4::from_cols3(
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, 0.0),
+        );
+        assert_eq!(identity, Mat4::new_identity());
+
+        let right = Vec3::new(0.0, 0.0, -1.0);
+        let up = Vec3::new(0.0, 1.0, 0.0);
+        let forward = Vec3::new(1.0, 0.0, 0.0);
+        let origin = Vec3::new(5.0, 6.0, 7.0);
+        assert_eq!(
+            Mat4::from_cols3(right, up, forward, origin),
+            Mat4::from_basis(right, up, forward, origin)
+        );
+    }
+
+    #[test]
+    fn test_flat_round_trip() {
+        let values: [f32; 16] = core::array::from_fn(|i| i as f32 * 0.5 - 3.0);
+        for order in [MajorOrder::RowMajor, MajorOrder::ColMajor] {
+            assert_eq!(Mat4::from_flat(values, order).to_flat(order), values);
+        }
+    }
+
+    #[test]
+    fn test_flat_orders_are_transposes() {
+        let values: [f32; 16] = core::array::from_fn(|i| i as f32);
+        let rows = Mat4::from_flat(values, MajorOrder::RowMajor);
+        let cols = Mat4::from_flat(values, MajorOrder::ColMajor);
+        assert_eq!(rows.data[0], [0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(rows.transpose(), cols);
+        assert_eq!(
+            rows.to_flat(MajorOrder::ColMajor),
+            rows.transpose().to_flat(MajorOrder::RowMajor)
+        );
+    }
+
+    #[test]
+    fn test_world_to_ndc() {
+        // Right-handed perspective, 90 degree fov, aspect 1, near 1, far 10.
+        let projection = Mat4 {
+            data: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, -11.0 / 9.0, -20.0 / 9.0],
+                [0.0, 0.0, -1.0, 0.0],
+            ],
+        };
+        let view = Mat4::translate(0.0, 0.0, -5.0);
+        let view_projection = projection.mul(&view);
+
+        let center = view_projection
+            .world_to_ndc(Vec3::new(0.0, 0.0, 0.0))
+            .unwrap();
+        assert!(center.x.abs() < EPSILON);
+        assert!(center.y.abs() < EPSILON);
+        assert!((center.z - 7.0 / 9.0).abs() < EPSILON);
+
+        let near = projection.world_to_ndc(Vec3::new(1.0, 0.0, -1.0)).unwrap();
+        assert!((near.x - 1.0).abs() < EPSILON);
+        assert!((near.z + 1.0).abs() < EPSILON);
+
+        assert_eq!(projection.world_to_ndc(Vec3::new(1.0, 2.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_invert_translation_rotation() {
+        let mat = Mat4::translate(3.0, -2.0, 5.0).mul(&Mat4::rotate_y(0.8));
+        let inverted = mat.invert().unwrap();
+        let identity = Mat4::new_identity();
+        let product = mat.mul(&inverted);
+        let reverse = inverted.mul(&mat);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((product.data[i][j] - identity.data[i][j]).abs() < EPSILON);
+                assert!((reverse.data[i][j] - identity.data[i][j]).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invert_singular() {
+        let mut mat = Mat4::translate(1.0, 2.0, 3.0);
+        mat.data[1] = [0.0; 4];
+        assert_eq!(mat.invert(), None);
+    }
+
+    #[test]
+    fn test_transform_builder() {
+        let built = TransformBuilder::new()
+            .translate(1.0, 2.0, 3.0)
+            .rotate_y(0.5)
+            .scale(2.0, 2.0, 2.0)
+            .build();
+        let expected = Mat4::translate(1.0, 2.0, 3.0)
+            .mul(&Mat4::rotate_y(0.5))
+            .mul(&Mat4::scale(2.0, 2.0, 2.0));
+        assert_eq!(built, expected);
+        assert_eq!(TransformBuilder::default().build(), Mat4::new_identity());
+    }
+
+    #[test]
+    fn test_mul_vec4_translate() {
+        let result = Mat4::translate(1.0, 2.0, 3.0).mul_vec4(&Vec4::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(result, Vec4::new(1.0, 2.0, 3.0, 1.0));
+    }
+
+    #[test]
+    fn test_mul_vec4_rotate_z() {
+        let rotation = Mat4::rotate_z(std::f32::consts::PI / 2.0);
+        let result = rotation.mul_vec4(&Vec4::new(1.0, 0.0, 0.0, 0.0));
+        assert!(result.sub(&Vec4::new(0.0, 1.0, 0.0, 0.0)).length() < EPSILON);
+    }
+
+    #[test]
+    fn test_mat3_from_mat4() {
+        let angle = 0.6;
+        let mat3: Mat3 = Mat4::translate(4.0, 5.0, 6.0)
+            .mul(&Mat4::rotate_z(angle))
+            .into();
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let expected = [[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]];
+        for (row, expected_row) in mat3.data.iter().zip(expected.iter()) {
+            for (value, expected_value) in row.iter().zip(expected_row.iter()) {
+                assert!((value - expected_value).abs() < EPSILON);
+            }
+        }
+        assert_eq!(Mat3::from_mat4(&Mat4::rotate_z(angle)).data, mat3.data);
+    }
+
+    #[test]
+    fn test_mat3_identity() {
+        let identity = Mat3::new_identity();
+        assert_eq!(
+            identity.data,
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+        );
+        assert_eq!(identity.add(&Mat3::new_zero()), identity);
+        assert_eq!(identity.determinant(), 1.0);
+        assert_eq!(identity.invert(), Some(identity));
+    }
+
+    #[test]
+    fn test_mat3_mul_identity() {
+        let mat = Mat3 {
+            data: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+        };
+        assert_eq!(mat.mul(&Mat3::new_identity()), mat);
+        assert_eq!(Mat3::new_identity().mul(&mat), mat);
+    }
+
+    #[test]
+    fn test_mat3_mul() {
+        let mat1 = Mat3 {
+            data: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+        };
+        let mat2 = Mat3 {
+            data: [[9.0, 8.0, 7.0], [6.0, 5.0, 4.0], [3.0, 2.0, 1.0]],
+        };
+        assert_eq!(
+            mat1.mul(&mat2).data,
+            [[30.0, 24.0, 18.0], [84.0, 69.0, 54.0], [138.0, 114.0, 90.0]]
+        );
+        assert_eq!(mat1.transpose().data[0], [1.0, 4.0, 7.0]);
+        assert_eq!(
+            mat1.mul_vec3(&Vec3::new(1.0, 0.0, -1.0)),
+            Vec3::new(-2.0, -2.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn test_mat3_invert() {
+        let mat = Mat3 {
+            data: [[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]],
+        };
+        assert_eq!(mat.determinant(), 25.0);
+        let product = mat.mul(&mat.invert().unwrap());
+        for (row, expected_row) in product.data.iter().zip(Mat3::new_identity().data.iter()) {
+            for (value, expected) in row.iter().zip(expected_row.iter()) {
+                assert!((value - expected).abs() < EPSILON);
+            }
+        }
+
+        let singular = Mat3 {
+            data: [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+        };
+        assert_eq!(singular.invert(), None);
+    }
+
+    #[test]
+    fn test_mat2_rotation() {
+        let rotated = Mat2::rotation(std::f32::consts::FRAC_PI_2).mul_vec2(&Vec2::new(1.0, 0.0));
+        assert!(rotated.x.abs() < EPSILON);
+        assert!((rotated.y - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_mat2_invert() {
+        let mat = Mat2 {
+            data: [[4.0, 7.0], [2.0, 6.0]],
+        };
+        assert_eq!(mat.determinant(), 10.0);
+        let inverse = mat.invert().unwrap();
+        let expected = [[0.6, -0.7], [-0.2, 0.4]];
+        for (row, expected_row) in inverse.data.iter().zip(expected.iter()) {
+            for (value, expected) in row.iter().zip(expected_row.iter()) {
+                assert!((value - expected).abs() < EPSILON);
+            }
+        }
+        assert_eq!(mat.mul(&Mat2::new_identity()), mat);
+        assert_eq!(mat.transpose().data, [[4.0, 2.0], [7.0, 6.0]]);
+
+        let singular = Mat2 {
+            data: [[1.0, 2.0], [2.0, 4.0]],
+        };
+        assert_eq!(singular.invert(), None);
+        assert_eq!(Mat2::new_zero().invert(), None);
+    }
+
+    #[test]
+    fn test_mat3_invert_small_scale() {
+        let tiny = Mat3 {
+            data: [[0.01, 0.0, 0.0], [0.0, 0.01, 0.0], [0.0, 0.0, 0.01]],
+        };
+        let inverse = tiny.invert().unwrap();
+        for (i, row) in inverse.data.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                let expected = if i == j { 100.0 } else { 0.0 };
+                assert!((value - expected).abs() < 1e-3);
+            }
+        }
+
+        let tiny_singular = Mat3 {
+            data: [[0.01, 0.02, 0.0], [0.02, 0.04, 0.0], [0.0, 0.0, 0.01]],
+        };
+        assert_eq!(tiny_singular.invert(), None);
+    }
+
+    #[test]
+    fn test_to_mat3() {
+        let mat = Mat4::translate(1.0, 2.0, 3.0).mul(&Mat4::scale(2.0, 3.0, 4.0));
+        assert_eq!(
+            mat.to_mat3().data,
+            [[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [0.0, 0.0, 4.0]]
+        );
+    }
+
+    fn assert_mat4_near(actual: &Mat4, expected: &Mat4) {
+        for (row, expected_row) in actual.data.iter().zip(expected.data.iter()) {
+            for (value, expected) in row.iter().zip(expected_row.iter()) {
+                assert!(
+                    (value - expected).abs() < EPSILON,
+                    "{actual:?} != {expected:?}"
+                );
+            }
+        }
+    }
+
+    fn scrambled_pair() -> (Mat4, Mat4) {
+        let a = Mat4 {
+            data: core::array::from_fn(|i| {
+                core::array::from_fn(|j| ((i * 7 + j * 3) % 11) as f32 * 0.37 - 1.5)
+            }),
+        };
+        let b = Mat4 {
+            data: core::array::from_fn(|i| {
+                core::array::from_fn(|j| ((i * 5 + j * 9) % 13) as f32 * -0.21 + 0.8)
+            }),
+        };
+        (a, b)
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_mul_simd_matches_scalar() {
+        let (a, b) = scrambled_pair();
+        assert_mat4_near(&a.mul_simd(&b), &a.mul(&b));
+        assert_mat4_near(&b.mul_simd(&a), &b.mul(&a));
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_mul_avx_matches_scalar() {
+        if !std::arch::is_x86_feature_detected!("avx") {
+            return;
+        }
+        let (a, b) = scrambled_pair();
+        unsafe {
+            assert_mat4_near(&a.mul_avx(&b), &a.mul(&b));
+            assert_mat4_near(&b.mul_avx(&a), &b.mul(&a));
+            assert_mat4_near(&a.mul_avx(&Mat4::new_identity()), &a);
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn test_mul_neon_matches_scalar() {
+        let (a, b) = scrambled_pair();
+        assert_mat4_near(&a.mul_neon(&b), &a.mul(&b));
+        assert_mat4_near(&b.mul_neon(&a), &b.mul(&a));
+    }
+
     #[test]
     fn test_mul_auto() {
         let mat1 = Mat4 {
@@ -363,8 +2630,37 @@ mod tests {
         };
         let mat2 = Mat4::new_identity();
         let result = mat1.mul_auto(&mat2);
-        let expected = mat1.clone();
+        let expected = mat1;
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_mul_batch() {
+        let (a, b) = scrambled_pair();
+        let lhs = [a, b, Mat4::rotate_x(0.3), Mat4::new_identity()];
+        let rhs = [b, a, Mat4::translate(1.0, 2.0, 3.0), a];
+        let mut out = [Mat4::new_zero(); 4];
+        Mat4::mul_batch(&lhs, &rhs, &mut out);
+        for ((l, r), result) in lhs.iter().zip(rhs.iter()).zip(out.iter()) {
+            assert_mat4_near(result, &Mat4::mul(l, r));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_batch_length_mismatch() {
+        let lhs = [Mat4::new_identity(); 3];
+        let rhs = [Mat4::new_identity(); 2];
+        let mut out = [Mat4::new_zero(); 3];
+        Mat4::mul_batch(&lhs, &rhs, &mut out);
+    }
+
+    #[test]
+    fn test_mul_auto_matches_scalar() {
+        let (a, b) = scrambled_pair();
+        assert_mat4_near(&a.mul_auto(&b), &a.mul(&b));
+        // The second call goes through the cached detection result.
+        assert_mat4_near(&b.mul_auto(&a), &b.mul(&a));
+    }
 }
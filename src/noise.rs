@@ -0,0 +1,186 @@
+use crate::vector::{Vec2, Vec3};
+
+// Ken Perlin's reference permutation; fixed so noise is reproducible everywhere.
+const PERM: [u8; 256] = [
+    151, 160, 137, 91, 90, 15, 131, 13, 201, 95, 96, 53, 194, 233, 7, 225, 140, 36, 103, 30, 69,
+    142, 8, 99, 37, 240, 21, 10, 23, 190, 6, 148, 247, 120, 234, 75, 0, 26, 197, 62, 94, 252, 219,
+    203, 117, 35, 11, 32, 57, 177, 33, 88, 237, 149, 56, 87, 174, 20, 125, 136, 171, 168, 68, 175,
+    74, 165, 71, 134, 139, 48, 27, 166, 77, 146, 158, 231, 83, 111, 229, 122, 60, 211, 133, 230,
+    220, 105, 92, 41, 55, 46, 245, 40, 244, 102, 143, 54, 65, 25, 63, 161, 1, 216, 80, 73, 209, 76,
+    132, 187, 208, 89, 18, 169, 200, 196, 135, 130, 116, 188, 159, 86, 164, 100, 109, 198, 173,
+    186, 3, 64, 52, 217, 226, 250, 124, 123, 5, 202, 38, 147, 118, 126, 255, 82, 85, 212, 207, 206,
+    59, 227, 47, 16, 58, 17, 182, 189, 28, 42, 223, 183, 170, 213, 119, 248, 152, 2, 44, 154, 163,
+    70, 221, 153, 101, 155, 167, 43, 172, 9, 129, 22, 39, 253, 19, 98, 108, 110, 79, 113, 224, 232,
+    178, 185, 112, 104, 218, 246, 97, 228, 251, 34, 242, 193, 238, 210, 144, 12, 191, 179, 162,
+    241, 81, 51, 145, 235, 249, 14, 239, 107, 49, 192, 214, 31, 181, 199, 106, 157, 184, 84, 204,
+    176, 115, 121, 50, 45, 127, 4, 150, 254, 138, 236, 205, 93, 222, 114, 67, 29, 24, 72, 243, 141,
+    128, 195, 78, 66, 215, 61, 156, 180,
+];
+
+const GRAD3: [[f32; 3]; 12] = [
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, -1.0, 0.0],
+    [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [1.0, 0.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0],
+];
+
+fn hash(i: i32) -> usize {
+    PERM[(i & 255) as usize] as usize
+}
+
+fn gradient_index(i: i32, j: i32, k: i32) -> usize {
+    hash(i + hash(j + hash(k) as i32) as i32) % 12
+}
+
+/// 2D simplex noise, roughly in [-1, 1].
+pub fn simplex_2d(p: Vec2) -> f32 {
+    let f2 = 0.5 * (3.0f32.sqrt() - 1.0);
+    let g2 = (3.0 - 3.0f32.sqrt()) / 6.0;
+
+    let skew = (p.x + p.y) * f2;
+    let i = (p.x + skew).floor() as i32;
+    let j = (p.y + skew).floor() as i32;
+    let unskew = (i + j) as f32 * g2;
+    let x0 = p.x - (i as f32 - unskew);
+    let y0 = p.y - (j as f32 - unskew);
+
+    let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+    let corners = [
+        (x0, y0, 0, 0),
+        (x0 - i1 as f32 + g2, y0 - j1 as f32 + g2, i1, j1),
+        (x0 - 1.0 + 2.0 * g2, y0 - 1.0 + 2.0 * g2, 1, 1),
+    ];
+
+    let mut total = 0.0;
+    for (x, y, di, dj) in corners {
+        let t = 0.5 - x * x - y * y;
+        if t > 0.0 {
+            let g = GRAD3[gradient_index(i + di, j + dj, 0)];
+            total += t * t * t * t * (g[0] * x + g[1] * y);
+        }
+    }
+    70.0 * total
+}
+
+/// 3D simplex noise, roughly in [-1, 1].
+pub fn simplex_3d(p: Vec3) -> f32 {
+    let f3 = 1.0 / 3.0;
+    let g3 = 1.0 / 6.0;
+
+    let skew = (p.x + p.y + p.z) * f3;
+    let i = (p.x + skew).floor() as i32;
+    let j = (p.y + skew).floor() as i32;
+    let k = (p.z + skew).floor() as i32;
+    let unskew = (i + j + k) as f32 * g3;
+    let x0 = p.x - (i as f32 - unskew);
+    let y0 = p.y - (j as f32 - unskew);
+    let z0 = p.z - (k as f32 - unskew);
+
+    // Pick the simplex traversal order from the ranking of the offsets.
+    let ((i1, j1, k1), (i2, j2, k2)) = if x0 >= y0 {
+        if y0 >= z0 {
+            ((1, 0, 0), (1, 1, 0))
+        } else if x0 >= z0 {
+            ((1, 0, 0), (1, 0, 1))
+        } else {
+            ((0, 0, 1), (1, 0, 1))
+        }
+    } else if y0 < z0 {
+        ((0, 0, 1), (0, 1, 1))
+    } else if x0 < z0 {
+        ((0, 1, 0), (0, 1, 1))
+    } else {
+        ((0, 1, 0), (1, 1, 0))
+    };
+
+    let corners = [
+        (x0, y0, z0, 0, 0, 0),
+        (
+            x0 - i1 as f32 + g3,
+            y0 - j1 as f32 + g3,
+            z0 - k1 as f32 + g3,
+            i1,
+            j1,
+            k1,
+        ),
+        (
+            x0 - i2 as f32 + 2.0 * g3,
+            y0 - j2 as f32 + 2.0 * g3,
+            z0 - k2 as f32 + 2.0 * g3,
+            i2,
+            j2,
+            k2,
+        ),
+        (
+            x0 - 1.0 + 3.0 * g3,
+            y0 - 1.0 + 3.0 * g3,
+            z0 - 1.0 + 3.0 * g3,
+            1,
+            1,
+            1,
+        ),
+    ];
+
+    let mut total = 0.0;
+    for (x, y, z, di, dj, dk) in corners {
+        let t = 0.6 - x * x - y * y - z * z;
+        if t > 0.0 {
+            let g = GRAD3[gradient_index(i + di, j + dj, k + dk)];
+            total += t * t * t * t * (g[0] * x + g[1] * y + g[2] * z);
+        }
+    }
+    32.0 * total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplex_reproducible() {
+        let p2 = Vec2::new(3.7, -12.25);
+        let p3 = Vec3::new(0.3, 8.1, -2.6);
+        assert_eq!(simplex_2d(p2), simplex_2d(p2));
+        assert_eq!(simplex_3d(p3), simplex_3d(p3));
+        assert_ne!(simplex_2d(p2), simplex_2d(Vec2::new(3.9, -12.25)));
+    }
+
+    #[test]
+    fn test_simplex_range() {
+        for i in 0..2000 {
+            let t = i as f32 * 0.137;
+            let n2 = simplex_2d(Vec2::new(t, t * 0.61 - 40.0));
+            let n3 = simplex_3d(Vec3::new(t * 0.73, -t, t * 0.29 + 5.0));
+            assert!((-1.0..=1.0).contains(&n2), "2d out of range: {}", n2);
+            assert!((-1.0..=1.0).contains(&n3), "3d out of range: {}", n3);
+        }
+    }
+
+    #[test]
+    fn test_simplex_continuity() {
+        let step = 1e-3;
+        for i in 0..500 {
+            let t = i as f32 * 0.291;
+            let p2 = Vec2::new(t, -t * 0.5);
+            let d2 = simplex_2d(p2) - simplex_2d(Vec2::new(p2.x + step, p2.y));
+            let p3 = Vec3::new(t, t * 0.3, -t);
+            let d3 = simplex_3d(p3) - simplex_3d(Vec3::new(p3.x, p3.y + step, p3.z));
+            assert!(d2.abs() < 0.05);
+            assert!(d3.abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_simplex_zero_at_lattice_origin() {
+        assert_eq!(simplex_2d(Vec2::new(0.0, 0.0)), 0.0);
+        assert_eq!(simplex_3d(Vec3::new(0.0, 0.0, 0.0)), 0.0);
+    }
+}
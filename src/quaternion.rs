@@ -0,0 +1,244 @@
+use crate::vector::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quat {
+        Quat { x, y, z, w }
+    }
+
+    pub fn identity() -> Quat {
+        Quat {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+
+    /// Rotation of `angle` radians about `axis`, which must be unit length.
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Quat {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Quat {
+            x: axis.x * sin,
+            y: axis.y * sin,
+            z: axis.z * sin,
+            w: cos,
+        }
+    }
+
+    /// Hamilton product: the rotation `other` followed by `self`.
+    pub fn mul(&self, other: &Quat) -> Quat {
+        Quat {
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        }
+    }
+
+    /// Negates the vector part; the inverse rotation for a unit quaternion.
+    pub fn conjugate(&self) -> Quat {
+        Quat {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    pub fn dot(&self, other: &Quat) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quat {
+        let len = self.length();
+        if len > 0.0 {
+            let inv = 1.0 / len;
+            Quat {
+                x: self.x * inv,
+                y: self.y * inv,
+                z: self.z * inv,
+                w: self.w * inv,
+            }
+        } else {
+            *self
+        }
+    }
+
+    /// Component-wise lerp followed by normalization, taking the shortest path by
+    /// flipping `other` when the two quaternions lie in opposite hemispheres.
+    pub fn nlerp(&self, other: &Quat, t: f32) -> Quat {
+        let sign = if self.dot(other) < 0.0 { -1.0 } else { 1.0 };
+        Quat {
+            x: self.x + (other.x * sign - self.x) * t,
+            y: self.y + (other.y * sign - self.y) * t,
+            z: self.z + (other.z * sign - self.z) * t,
+            w: self.w + (other.w * sign - self.w) * t,
+        }
+        .normalize()
+    }
+
+    /// Rotates `v` by this unit quaternion. Expands the `q * v * q^-1` sandwich to
+    /// `v + w * t + u x t` with `u = (x, y, z)` and `t = 2 * (u x v)`.
+    pub fn rotate_vec3(&self, v: &Vec3) -> Vec3 {
+        let u = Vec3::new(self.x, self.y, self.z);
+        let t = u.cross(v) * 2.0;
+        *v + t * self.w + u.cross(&t)
+    }
+
+    /// Spherical linear interpolation at constant angular speed, taking the
+    /// shortest path. Nearly parallel inputs fall back to `nlerp`, where
+    /// `sin(angle)` would be too small to divide by.
+    pub fn slerp(&self, other: &Quat, t: f32) -> Quat {
+        let mut cos_angle = self.dot(other);
+        let mut end = *other;
+        if cos_angle < 0.0 {
+            cos_angle = -cos_angle;
+            end = Quat::new(-other.x, -other.y, -other.z, -other.w);
+        }
+        if cos_angle > 1.0 - 1e-4 {
+            return self.nlerp(&end, t);
+        }
+        let angle = cos_angle.acos();
+        let sin_angle = angle.sin();
+        let a = ((1.0 - t) * angle).sin() / sin_angle;
+        let b = (t * angle).sin() / sin_angle;
+        Quat {
+            x: self.x * a + end.x * b,
+            y: self.y * a + end.y * b,
+            z: self.z * a + end.z * b,
+            w: self.w * a + end.w * b,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::EPSILON;
+
+    fn rotation_z(angle: f32) -> Quat {
+        Quat::new(0.0, 0.0, (angle * 0.5).sin(), (angle * 0.5).cos())
+    }
+
+    #[test]
+    fn test_quat_identity() {
+        let q = Quat::identity();
+        assert_eq!(q, Quat::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(q.length(), 1.0);
+    }
+
+    #[test]
+    fn test_quat_from_axis_angle() {
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 0.8);
+        assert!((q.dot(&rotation_z(0.8)) - 1.0).abs() < EPSILON);
+        assert_eq!(q.mul(&Quat::identity()), q);
+    }
+
+    #[test]
+    fn test_quat_mul_adds_angles() {
+        let axis = Vec3::new(0.0, 0.6, 0.8);
+        let a = Quat::from_axis_angle(axis, 0.5);
+        let b = Quat::from_axis_angle(axis, 1.1);
+        let sum = Quat::from_axis_angle(axis, 1.6);
+        assert!((a.mul(&b).dot(&sum) - 1.0).abs() < EPSILON);
+        assert!((b.mul(&a).dot(&sum) - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_quat_conjugate() {
+        let q = Quat::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), 2.3);
+        let product = q.mul(&q.conjugate());
+        assert!((product.dot(&Quat::identity()) - 1.0).abs() < EPSILON);
+        assert!((product.length() - 1.0).abs() < EPSILON);
+        assert_eq!(q.conjugate().conjugate(), q);
+    }
+
+    #[test]
+    fn test_quat_dot() {
+        let a = Quat::new(1.0, 2.0, 3.0, 4.0);
+        let b = Quat::new(5.0, 6.0, 7.0, 8.0);
+        assert_eq!(a.dot(&b), 70.0);
+    }
+
+    #[test]
+    fn test_quat_normalize() {
+        let q = Quat::new(0.0, 3.0, 0.0, 4.0).normalize();
+        assert_eq!(q, Quat::new(0.0, 0.6, 0.0, 0.8));
+    }
+
+    #[test]
+    fn test_quat_nlerp_endpoints() {
+        let a = Quat::identity();
+        let b = rotation_z(std::f32::consts::PI / 2.0);
+        let start = a.nlerp(&b, 0.0);
+        let end = a.nlerp(&b, 1.0);
+        assert!((start.dot(&a) - 1.0).abs() < EPSILON);
+        assert!((end.dot(&b) - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_quat_nlerp_unit_and_shortest_path() {
+        let a = rotation_z(0.2);
+        let b = rotation_z(1.4);
+        let negated_b = Quat::new(-b.x, -b.y, -b.z, -b.w);
+        for step in 0..=10 {
+            let t = step as f32 / 10.0;
+            let q = a.nlerp(&b, t);
+            assert!((q.length() - 1.0).abs() < EPSILON);
+            let flipped = a.nlerp(&negated_b, t);
+            assert!((q.dot(&flipped) - 1.0).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_quat_rotate_vec3() {
+        let rotated =
+            rotation_z(std::f32::consts::FRAC_PI_2).rotate_vec3(&Vec3::new(1.0, 0.0, 0.0));
+        assert!(rotated.sub(&Vec3::new(0.0, 1.0, 0.0)).length() < EPSILON);
+
+        let v = Vec3::new(0.3, -2.0, 7.5);
+        assert_eq!(Quat::identity().rotate_vec3(&v), v);
+
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), 1.3);
+        let sandwich = q.mul(&Quat::new(v.x, v.y, v.z, 0.0)).mul(&q.conjugate());
+        let expected = Vec3::new(sandwich.x, sandwich.y, sandwich.z);
+        assert!(q.rotate_vec3(&v).sub(&expected).length() < EPSILON * 10.0);
+    }
+
+    #[test]
+    fn test_quat_slerp() {
+        let a = rotation_z(0.0);
+        let b = rotation_z(std::f32::consts::FRAC_PI_2);
+        assert!((a.slerp(&b, 0.0).dot(&a) - 1.0).abs() < EPSILON);
+        assert!((a.slerp(&b, 1.0).dot(&b) - 1.0).abs() < EPSILON);
+
+        let half = a.slerp(&b, 0.5);
+        assert!((half.dot(&rotation_z(std::f32::consts::FRAC_PI_4)) - 1.0).abs() < EPSILON);
+        assert!((half.length() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_quat_slerp_shortest_path_and_parallel() {
+        let a = rotation_z(0.3);
+        let b = rotation_z(1.1);
+        let negated_b = Quat::new(-b.x, -b.y, -b.z, -b.w);
+        let q = a.slerp(&negated_b, 0.5);
+        assert!((q.dot(&rotation_z(0.7)).abs() - 1.0).abs() < EPSILON);
+
+        let nearly = rotation_z(0.3 + 1e-4);
+        let q = a.slerp(&nearly, 0.5);
+        assert!(q.x.is_finite() && (q.length() - 1.0).abs() < EPSILON);
+    }
+}
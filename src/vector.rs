@@ -5,6 +5,10 @@ use core::arch::aarch64::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::math;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vec2 {
     pub x: f32,
@@ -19,6 +23,7 @@ pub struct Vec3 {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Vec4 {
     pub x: f32,
     pub y: f32,
@@ -57,7 +62,19 @@ impl Vec2 {
     }
 
     pub fn length(&self) -> f32 {
-        (self.x * self.x + self.y * self.y).sqrt()
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn distance(&self, other: &Vec2) -> f32 {
+        self.distance_squared(other).sqrt()
+    }
+
+    pub fn distance_squared(&self, other: &Vec2) -> f32 {
+        self.sub(other).length_squared()
     }
 
     pub fn unit(&self) -> Vec2 {
@@ -68,6 +85,136 @@ impl Vec2 {
             *self
         }
     }
+
+    pub fn clamp_length(&self, max: f32) -> Vec2 {
+        let len = self.length();
+        if len > max {
+            self.mul_scalar(max / len)
+        } else {
+            *self
+        }
+    }
+
+    /// Component-wise lerp with a separate factor per axis.
+    pub fn lerp_vec(&self, other: &Vec2, t: Vec2) -> Vec2 {
+        Vec2 {
+            x: math::lerp(self.x, other.x, t.x),
+            y: math::lerp(self.y, other.y, t.y),
+        }
+    }
+
+    /// Unit vector at `angle` radians from the x axis, i.e. the complex number e^(i*angle).
+    pub fn from_angle(angle: f32) -> Vec2 {
+        Vec2 {
+            x: angle.cos(),
+            y: angle.sin(),
+        }
+    }
+
+    /// Multiplies the vectors as complex numbers `x + iy`, composing their rotations.
+    pub fn complex_mul(&self, other: &Vec2) -> Vec2 {
+        Vec2 {
+            x: self.x * other.x - self.y * other.y,
+            y: self.x * other.y + self.y * other.x,
+        }
+    }
+
+    /// Rotates towards the direction of `target` by at most `max_radians`, keeping
+    /// this vector's length. Returns `self` unchanged if either vector is zero. A
+    /// negative `max_radians` is treated as 0, so it never rotates away.
+    pub fn rotate_towards(&self, target: Vec2, max_radians: f32) -> Vec2 {
+        let max_radians = math::max(max_radians, 0.0);
+        let len = self.length();
+        if len == 0.0 || target.length() == 0.0 {
+            return *self;
+        }
+        let delta = target.y.atan2(target.x) - self.y.atan2(self.x);
+        // Wrap into [-PI, PI] so the rotation takes the shorter way round.
+        let delta = (delta + math::PI).rem_euclid(math::TAU) - math::PI;
+        if delta.abs() <= max_radians {
+            return target.unit().mul_scalar(len);
+        }
+        let step = math::clamp(delta, -max_radians, max_radians);
+        self.complex_mul(&Vec2::from_angle(step))
+    }
+
+    /// Largest size with `content`'s aspect ratio that fits inside `container`
+    /// (letterboxing). Zero when `content` has a zero or negative side.
+    pub fn aspect_fit(content: Vec2, container: Vec2) -> Vec2 {
+        Vec2::aspect_scale(content, container, math::min)
+    }
+
+    /// Smallest size with `content`'s aspect ratio that covers `container`
+    /// (cropping). Zero when `content` has a zero or negative side.
+    pub fn aspect_fill(content: Vec2, container: Vec2) -> Vec2 {
+        Vec2::aspect_scale(content, container, math::max)
+    }
+
+    fn aspect_scale(content: Vec2, container: Vec2, pick: fn(f32, f32) -> f32) -> Vec2 {
+        if content.x <= 0.0 || content.y <= 0.0 {
+            return Vec2::new(0.0, 0.0);
+        }
+        content.mul_scalar(pick(container.x / content.x, container.y / content.y))
+    }
+
+    /// Adds `a[i] + b[i]` into `out[i]`, two vectors per 4-wide register.
+    pub fn add_pairs(a: &[Vec2], b: &[Vec2], out: &mut [Vec2]) {
+        assert_eq!(a.len(), b.len());
+        assert_eq!(a.len(), out.len());
+        let pairs = a
+            .chunks_exact(2)
+            .zip(b.chunks_exact(2))
+            .zip(out.chunks_exact_mut(2));
+        for ((pa, pb), po) in pairs {
+            let sum = add_packed(
+                [pa[0].x, pa[0].y, pa[1].x, pa[1].y],
+                [pb[0].x, pb[0].y, pb[1].x, pb[1].y],
+            );
+            po[0] = Vec2::new(sum[0], sum[1]);
+            po[1] = Vec2::new(sum[2], sum[3]);
+        }
+        if a.len() % 2 == 1 {
+            let last = a.len() - 1;
+            out[last] = a[last].add(&b[last]);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn add_packed(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    let mut result = [0.0; 4];
+    unsafe {
+        let sum = _mm_add_ps(_mm_loadu_ps(a.as_ptr()), _mm_loadu_ps(b.as_ptr()));
+        _mm_storeu_ps(result.as_mut_ptr(), sum);
+    }
+    result
+}
+
+/// Sum of the four lanes using SSE1 only (`_mm_hadd_ps` would need SSE3, which
+/// is not part of the x86_64 baseline): fold the high pair onto the low pair,
+/// then lane 1 onto lane 0.
+#[cfg(target_arch = "x86_64")]
+fn horizontal_add(v: __m128) -> f32 {
+    unsafe {
+        let pairs = _mm_add_ps(v, _mm_movehl_ps(v, v));
+        let total = _mm_add_ss(pairs, _mm_shuffle_ps::<0b01>(pairs, pairs));
+        _mm_cvtss_f32(total)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn add_packed(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    let mut result = [0.0; 4];
+    unsafe {
+        let sum = vaddq_f32(vld1q_f32(a.as_ptr()), vld1q_f32(b.as_ptr()));
+        vst1q_f32(result.as_mut_ptr(), sum);
+    }
+    result
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn add_packed(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]]
 }
 
 impl Vec3 {
@@ -99,10 +246,42 @@ impl Vec3 {
         }
     }
 
+    /// Component-wise division.
+    pub fn div(&self, other: &Vec3) -> Vec3 {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    /// Component-wise division that yields `fallback` wherever the divisor is zero
+    /// instead of an infinity or NaN.
+    pub fn div_safe(&self, other: &Vec3, fallback: f32) -> Vec3 {
+        self.zip_map(other, |a, b| if b == 0.0 { fallback } else { a / b })
+    }
+
     pub fn dot(&self, other: &Vec3) -> f32 {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
 
+    #[cfg(target_arch = "x86_64")]
+    pub fn dot_simd(&self, other: &Vec3) -> f32 {
+        unsafe {
+            let a = _mm_set_ps(0.0, self.z, self.y, self.x);
+            let b = _mm_set_ps(0.0, other.z, other.y, other.x);
+            horizontal_add(_mm_mul_ps(a, b))
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    pub fn dot_simd(&self, other: &Vec3) -> f32 {
+        let a = [self.x, self.y, self.z, 0.0];
+        let b = [other.x, other.y, other.z, 0.0];
+        unsafe { vaddvq_f32(vmulq_f32(vld1q_f32(a.as_ptr()), vld1q_f32(b.as_ptr()))) }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn dot_simd(&self, other: &Vec3) -> f32 {
+        self.dot(other)
+    }
+
     pub fn cross(&self, other: &Vec3) -> Vec3 {
         Vec3 {
             x: self.y * other.z - self.z * other.y,
@@ -112,7 +291,19 @@ impl Vec3 {
     }
 
     pub fn length(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn distance(&self, other: &Vec3) -> f32 {
+        self.distance_squared(other).sqrt()
+    }
+
+    pub fn distance_squared(&self, other: &Vec3) -> f32 {
+        self.sub(other).length_squared()
     }
 
     pub fn normalize(&self) -> Vec3 {
@@ -123,6 +314,307 @@ impl Vec3 {
             *self
         }
     }
+
+    pub fn clamp_length(&self, max: f32) -> Vec3 {
+        let len = self.length();
+        if len > max {
+            self.mul_scalar(max / len)
+        } else {
+            *self
+        }
+    }
+
+    /// Rescales the vector so its length lies in [`min`, `max`], keeping its
+    /// direction. The zero vector has no direction and is returned unchanged.
+    pub fn clamp_length_range(&self, min: f32, max: f32) -> Vec3 {
+        let len = self.length();
+        if len == 0.0 {
+            *self
+        } else if len < min {
+            self.mul_scalar(min / len)
+        } else if len > max {
+            self.mul_scalar(max / len)
+        } else {
+            *self
+        }
+    }
+
+    /// Clamps each component into [-1, 1] independently, e.g. for stick input.
+    pub fn clamp_signed_unit(&self) -> Vec3 {
+        self.map(|v| math::clamp(v, -1.0, 1.0))
+    }
+
+    pub fn abs(&self) -> Vec3 {
+        Vec3 {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+
+    /// Per-component sign: -1, 0 or 1.
+    pub fn signum(&self) -> Vec3 {
+        Vec3 {
+            x: math::signum(self.x),
+            y: math::signum(self.y),
+            z: math::signum(self.z),
+        }
+    }
+
+    pub fn min(&self, other: &Vec3) -> Vec3 {
+        self.zip_map(other, math::min)
+    }
+
+    pub fn max(&self, other: &Vec3) -> Vec3 {
+        self.zip_map(other, math::max)
+    }
+
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Vec3 {
+        Vec3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    pub fn zip_map(&self, other: &Vec3, f: impl Fn(f32, f32) -> f32) -> Vec3 {
+        Vec3 {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+        }
+    }
+
+    /// True when both vectors point the same way, ignoring their lengths.
+    /// Zero-length vectors have no direction and never match.
+    pub fn same_direction(&self, other: &Vec3, epsilon: f32) -> bool {
+        let lengths = self.length() * other.length();
+        if lengths == 0.0 {
+            return false;
+        }
+        self.dot(other) / lengths >= 1.0 - epsilon
+    }
+
+    /// Pulls a point outside the sphere onto its surface; points inside are unchanged.
+    pub fn clamp_to_sphere(&self, center: Vec3, radius: f32) -> Vec3 {
+        center.add(&self.sub(&center).clamp_length(radius))
+    }
+
+    /// Interpolates towards `other`, clamping `t` to [0, 1].
+    pub fn lerp(&self, other: &Vec3, t: f32) -> Vec3 {
+        self.lerp_unclamped(other, math::clamp(t, 0.0, 1.0))
+    }
+
+    pub fn lerp_unclamped(&self, other: &Vec3, t: f32) -> Vec3 {
+        Vec3 {
+            x: math::lerp(self.x, other.x, t),
+            y: math::lerp(self.y, other.y, t),
+            z: math::lerp(self.z, other.z, t),
+        }
+    }
+
+    /// Returns `fallback` if any component is NaN or infinite, otherwise `self`.
+    pub fn sanitize(&self, fallback: Vec3) -> Vec3 {
+        if self.x.is_finite() && self.y.is_finite() && self.z.is_finite() {
+            *self
+        } else {
+            fallback
+        }
+    }
+
+    /// Mirrors this point through `pivot` (point reflection): `2 * pivot - self`.
+    pub fn reflect_point(&self, pivot: Vec3) -> Vec3 {
+        pivot.mul_scalar(2.0).sub(self)
+    }
+
+    /// Reflects this direction off a surface with unit `normal`.
+    pub fn reflect(&self, normal: &Vec3) -> Vec3 {
+        self.sub(&normal.mul_scalar(2.0 * self.dot(normal)))
+    }
+
+    /// Refracts this unit incident direction through a surface with unit `normal`
+    /// (facing against the incident ray), where `eta` is the ratio of refractive
+    /// indices. `None` on total internal reflection.
+    pub fn refract(&self, normal: &Vec3, eta: f32) -> Option<Vec3> {
+        let cos_incident = self.dot(normal);
+        let k = 1.0 - eta * eta * (1.0 - cos_incident * cos_incident);
+        if k < 0.0 {
+            return None;
+        }
+        Some(
+            self.mul_scalar(eta)
+                .sub(&normal.mul_scalar(eta * cos_incident + k.sqrt())),
+        )
+    }
+
+    /// Barycentric weights `(u, v, w)` of this point with respect to triangle `abc`,
+    /// so that `p = a*u + b*v + c*w` for points in the triangle's plane. Degenerate
+    /// triangles produce NaN or infinite weights; see `barycentric_safe`.
+    pub fn barycentric(&self, a: &Vec3, b: &Vec3, c: &Vec3) -> Vec3 {
+        let (d00, d01, d11, d20, d21) = self.barycentric_terms(a, b, c);
+        let denom = d00 * d11 - d01 * d01;
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Vec3::new(1.0 - v - w, v, w)
+    }
+
+    /// Like `barycentric`, but returns `None` when the triangle has (near) zero area.
+    pub fn barycentric_safe(&self, a: &Vec3, b: &Vec3, c: &Vec3) -> Option<Vec3> {
+        let (d00, d01, d11, _, _) = self.barycentric_terms(a, b, c);
+        let denom = d00 * d11 - d01 * d01;
+        if denom <= math::EPSILON * d00 * d11 {
+            return None;
+        }
+        Some(self.barycentric(a, b, c))
+    }
+
+    fn barycentric_terms(&self, a: &Vec3, b: &Vec3, c: &Vec3) -> (f32, f32, f32, f32, f32) {
+        let v0 = b.sub(a);
+        let v1 = c.sub(a);
+        let v2 = self.sub(a);
+        (
+            v0.dot(&v0),
+            v0.dot(&v1),
+            v1.dot(&v1),
+            v2.dot(&v0),
+            v2.dot(&v1),
+        )
+    }
+
+    /// Packs a unit normal into 10:10:10:2 bits: x in bits 0-9, y in 10-19, z in
+    /// 20-29, each mapped from [-1, 1] to [0, 1023]. The 2-bit w field is zero.
+    pub fn pack_normal_1010102(&self) -> u32 {
+        let quantize = |v: f32| (math::clamp(v * 0.5 + 0.5, 0.0, 1.0) * 1023.0).round() as u32;
+        quantize(self.x) | (quantize(self.y) << 10) | (quantize(self.z) << 20)
+    }
+
+    pub fn unpack_normal_1010102(packed: u32) -> Vec3 {
+        let dequantize = |bits: u32| (bits & 0x3ff) as f32 / 1023.0 * 2.0 - 1.0;
+        Vec3 {
+            x: dequantize(packed),
+            y: dequantize(packed >> 10),
+            z: dequantize(packed >> 20),
+        }
+    }
+
+    /// Lerps then normalizes: a cheap direction blend that is close to slerp for
+    /// small angles.
+    pub fn nlerp(&self, other: &Vec3, t: f32) -> Vec3 {
+        self.lerp(other, t).normalize()
+    }
+
+    /// Component-wise lerp with a separate factor per axis.
+    pub fn lerp_vec(&self, other: &Vec3, t: Vec3) -> Vec3 {
+        Vec3 {
+            x: math::lerp(self.x, other.x, t.x),
+            y: math::lerp(self.y, other.y, t.y),
+            z: math::lerp(self.z, other.z, t.z),
+        }
+    }
+
+    /// Cylindrical coordinates with the axis along +y: `theta` is measured in the xz
+    /// plane from +x towards +z, and `height` is the y coordinate.
+    pub fn from_cylindrical(radius: f32, theta: f32, height: f32) -> Vec3 {
+        Vec3 {
+            x: radius * theta.cos(),
+            y: height,
+            z: radius * theta.sin(),
+        }
+    }
+
+    /// Inverse of `from_cylindrical`, returning `(radius, theta, height)` with
+    /// `theta` in (-PI, PI].
+    pub fn to_cylindrical(&self) -> (f32, f32, f32) {
+        let radius = (self.x * self.x + self.z * self.z).sqrt();
+        (radius, self.z.atan2(self.x), self.y)
+    }
+
+    /// Index (0 = x, 1 = y, 2 = z) of the component with the largest magnitude.
+    pub fn major_axis(&self) -> usize {
+        let (ax, ay, az) = (self.x.abs(), self.y.abs(), self.z.abs());
+        if ax >= ay && ax >= az {
+            0
+        } else if ay >= az {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Integer coordinates of the grid cell of size `cell_size` containing this point.
+    pub fn cell(&self, cell_size: f32) -> (i32, i32, i32) {
+        (
+            (self.x / cell_size).floor() as i32,
+            (self.y / cell_size).floor() as i32,
+            (self.z / cell_size).floor() as i32,
+        )
+    }
+
+    pub fn yzx(&self) -> Vec3 {
+        Vec3::new(self.y, self.z, self.x)
+    }
+
+    pub fn zxy(&self) -> Vec3 {
+        Vec3::new(self.z, self.x, self.y)
+    }
+
+    /// Reorders components: component `i` of the result is component `order[i]` of
+    /// `self`. Panics if an index is greater than 2.
+    pub fn permute(&self, order: [usize; 3]) -> Vec3 {
+        let components = [self.x, self.y, self.z];
+        Vec3::new(
+            components[order[0]],
+            components[order[1]],
+            components[order[2]],
+        )
+    }
+
+    /// Deterministic hash of the component bit patterns, for use as a cache key.
+    pub fn bit_hash(&self) -> u64 {
+        math::hash_bits([self.x, self.y, self.z])
+    }
+
+    /// Signed unit axis along the largest-magnitude component. Ties resolve in `x`, `y`,
+    /// `z` order as in [`Vec3::major_axis`]. A zero vector, or one with a NaN component,
+    /// has no nearest axis and returns zero.
+    pub fn snap_to_nearest_axis(&self) -> Vec3 {
+        if (self.x == 0.0 && self.y == 0.0 && self.z == 0.0)
+            || self.x.is_nan()
+            || self.y.is_nan()
+            || self.z.is_nan()
+        {
+            return Vec3::new(0.0, 0.0, 0.0);
+        }
+        let sign = |v: f32| if v < 0.0 { -1.0 } else { 1.0 };
+        match self.major_axis() {
+            0 => Vec3::new(sign(self.x), 0.0, 0.0),
+            1 => Vec3::new(0.0, sign(self.y), 0.0),
+            _ => Vec3::new(0.0, 0.0, sign(self.z)),
+        }
+    }
+}
+
+/// Component-wise minimum and maximum of `points`, or `None` for an empty slice.
+pub fn bounds(points: &[Vec3]) -> Option<(Vec3, Vec3)> {
+    let first = *points.first()?;
+    Some(
+        points[1..]
+            .iter()
+            .fold((first, first), |(lo, hi), p| (lo.min(p), hi.max(p))),
+    )
+}
+
+/// Returns `1` for a right-handed frame and `-1` for a left-handed one, from the sign of
+/// the scalar triple product `right . (up x forward)`. Degenerate (coplanar) frames give `0`.
+pub fn frame_handedness(right: Vec3, up: Vec3, forward: Vec3) -> i8 {
+    let triple = right.dot(&up.cross(&forward));
+    if triple > 0.0 {
+        1
+    } else if triple < 0.0 {
+        -1
+    } else {
+        0
+    }
 }
 
 impl Vec4 {
@@ -170,9 +662,7 @@ impl Vec4 {
             let a = _mm_loadu_ps(self.as_ptr());
             let b = _mm_loadu_ps(other.as_ptr());
             let product = _mm_mul_ps(a, b);
-            let sum1 = _mm_hadd_ps(product, product);
-            let sum2 = _mm_hadd_ps(sum1, sum1);
-            _mm_cvtss_f32(sum2)
+            horizontal_add(product)
         }
     }
 
@@ -187,90 +677,539 @@ impl Vec4 {
             vgetq_lane_f32::<0>(sum2)
         }
     }
-    pub fn length(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
-    }
 
-    pub fn unit(&self) -> Vec4 {
-        let len = self.length();
-        if len > 0.0 {
-            self.mul_scalar(1.0 / len)
-        } else {
-            *self
-        }
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn dot_simd(&self, other: &Vec4) -> f32 {
+        self.dot(other)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Dot product through `dot_simd`. Every SIMD path needs only the baseline
+    /// features of its target (SSE on x86_64, NEON on aarch64), so no detection is
+    /// needed; other targets fall back to the scalar `dot`.
+    pub fn dot_auto(&self, other: &Vec4) -> f32 {
+        self.dot_simd(other)
+    }
 
-    #[test]
-    fn test_vec2_creation() {
-        let v = Vec2::new(1.0, 2.0);
-        assert_eq!(v.x, 1.0);
-        assert_eq!(v.y, 2.0);
+    #[cfg(target_arch = "x86_64")]
+    pub fn horizontal_sum(&self) -> f32 {
+        horizontal_add(unsafe { _mm_loadu_ps(self.as_ptr()) })
     }
 
-    #[test]
-    fn test_vec2_add() {
-        let v1 = Vec2::new(1.0, 2.0);
-        let v2 = Vec2::new(3.0, 4.0);
-        let result = v1.add(&v2);
-        assert_eq!(result, Vec2::new(4.0, 6.0));
+    #[cfg(target_arch = "aarch64")]
+    pub fn horizontal_sum(&self) -> f32 {
+        unsafe { vaddvq_f32(vld1q_f32(self.as_ptr())) }
     }
 
-    #[test]
-    fn test_vec2_sub() {
-        let v1 = Vec2::new(5.0, 6.0);
-        let v2 = Vec2::new(2.0, 3.0);
-        let result = v1.sub(&v2);
-        assert_eq!(result, Vec2::new(3.0, 3.0));
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn horizontal_sum(&self) -> f32 {
+        self.x + self.y + self.z + self.w
     }
 
-    #[test]
-    fn test_vec2_mul_scalar() {
-        let v = Vec2::new(1.0, 2.0);
-        let result = v.mul_scalar(3.0);
-        assert_eq!(result, Vec2::new(3.0, 6.0));
+    pub fn horizontal_product(&self) -> f32 {
+        self.x * self.y * self.z * self.w
     }
 
-    #[test]
-    fn test_vec2_dot() {
-        let v1 = Vec2::new(1.0, 2.0);
-        let v2 = Vec2::new(3.0, 4.0);
-        let result = v1.dot(&v2);
-        assert_eq!(result, 11.0);
+    pub fn max_component(&self) -> f32 {
+        math::max(math::max(self.x, self.y), math::max(self.z, self.w))
     }
 
-    #[test]
-    fn test_vec2_length() {
-        let v = Vec2::new(3.0, 4.0);
-        let result = v.length();
-        assert_eq!(result, 5.0); // sqrt(3^2 + 4^2) = 5
+    pub fn min_component(&self) -> f32 {
+        math::min(math::min(self.x, self.y), math::min(self.z, self.w))
     }
 
-    #[test]
-    fn test_vec2_unit() {
-        let v = Vec2::new(3.0, 4.0);
-        let unit_v = v.unit();
-        assert_eq!(unit_v.length(), 1.0);
+    #[cfg(target_arch = "x86_64")]
+    pub fn max_component_simd(&self) -> f32 {
+        unsafe {
+            let v = _mm_loadu_ps(self.as_ptr());
+            let pairs = _mm_max_ps(v, _mm_shuffle_ps::<0b01_00_11_10>(v, v));
+            let all = _mm_max_ps(pairs, _mm_shuffle_ps::<0b10_11_00_01>(pairs, pairs));
+            _mm_cvtss_f32(all)
+        }
     }
 
-    #[test]
-    fn test_vec3_creation() {
-        let v = Vec3::new(1.0, 2.0, 3.0);
-        assert_eq!(v.x, 1.0);
-        assert_eq!(v.y, 2.0);
-        assert_eq!(v.z, 3.0);
+    #[cfg(target_arch = "x86_64")]
+    pub fn min_component_simd(&self) -> f32 {
+        unsafe {
+            let v = _mm_loadu_ps(self.as_ptr());
+            let pairs = _mm_min_ps(v, _mm_shuffle_ps::<0b01_00_11_10>(v, v));
+            let all = _mm_min_ps(pairs, _mm_shuffle_ps::<0b10_11_00_01>(pairs, pairs));
+            _mm_cvtss_f32(all)
+        }
     }
 
-    #[test]
-    fn test_vec3_add() {
-        let v1 = Vec3::new(1.0, 2.0, 3.0);
-        let v2 = Vec3::new(4.0, 5.0, 6.0);
-        let result = v1.add(&v2);
-        assert_eq!(result, Vec3::new(5.0, 7.0, 9.0));
+    #[cfg(target_arch = "aarch64")]
+    pub fn max_component_simd(&self) -> f32 {
+        unsafe { vmaxvq_f32(vld1q_f32(self.as_ptr())) }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    pub fn min_component_simd(&self) -> f32 {
+        unsafe { vminvq_f32(vld1q_f32(self.as_ptr())) }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn max_component_simd(&self) -> f32 {
+        self.max_component()
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn min_component_simd(&self) -> f32 {
+        self.min_component()
+    }
+
+    /// Per-lane blend `self * (1 - w) + other * w`.
+    #[cfg(target_arch = "x86_64")]
+    pub fn mix(&self, other: &Vec4, weights: &Vec4) -> Vec4 {
+        let mut result = [0.0; 4];
+        unsafe {
+            let a = _mm_loadu_ps(self.as_ptr());
+            let b = _mm_loadu_ps(other.as_ptr());
+            let w = _mm_loadu_ps(weights.as_ptr());
+            let blended = _mm_add_ps(a, _mm_mul_ps(w, _mm_sub_ps(b, a)));
+            _mm_storeu_ps(result.as_mut_ptr(), blended);
+        }
+        Vec4::new(result[0], result[1], result[2], result[3])
+    }
+
+    /// Per-lane blend `self * (1 - w) + other * w`.
+    #[cfg(target_arch = "aarch64")]
+    pub fn mix(&self, other: &Vec4, weights: &Vec4) -> Vec4 {
+        let mut result = [0.0; 4];
+        unsafe {
+            let a = vld1q_f32(self.as_ptr());
+            let b = vld1q_f32(other.as_ptr());
+            let w = vld1q_f32(weights.as_ptr());
+            let blended = vfmaq_f32(a, w, vsubq_f32(b, a));
+            vst1q_f32(result.as_mut_ptr(), blended);
+        }
+        Vec4::new(result[0], result[1], result[2], result[3])
+    }
+
+    /// Per-lane blend `self * (1 - w) + other * w`.
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn mix(&self, other: &Vec4, weights: &Vec4) -> Vec4 {
+        Vec4 {
+            x: math::lerp(self.x, other.x, weights.x),
+            y: math::lerp(self.y, other.y, weights.y),
+            z: math::lerp(self.z, other.z, weights.z),
+            w: math::lerp(self.w, other.w, weights.w),
+        }
+    }
+
+    /// Approximate per-lane `1 / x`: the hardware estimate refined by one
+    /// Newton-Raphson step (`r * (2 - x * r)`).
+    #[cfg(target_arch = "x86_64")]
+    pub fn recip_simd(&self) -> Vec4 {
+        let mut result = [0.0; 4];
+        unsafe {
+            let x = _mm_loadu_ps(self.as_ptr());
+            let r = _mm_rcp_ps(x);
+            let refined = _mm_mul_ps(r, _mm_sub_ps(_mm_set1_ps(2.0), _mm_mul_ps(x, r)));
+            _mm_storeu_ps(result.as_mut_ptr(), refined);
+        }
+        Vec4::new(result[0], result[1], result[2], result[3])
+    }
+
+    /// Approximate per-lane `1 / x`: the hardware estimate refined by one
+    /// Newton-Raphson step (`r * (2 - x * r)`).
+    #[cfg(target_arch = "aarch64")]
+    pub fn recip_simd(&self) -> Vec4 {
+        let mut result = [0.0; 4];
+        unsafe {
+            let x = vld1q_f32(self.as_ptr());
+            let r = vrecpeq_f32(x);
+            let refined = vmulq_f32(r, vrecpsq_f32(x, r));
+            vst1q_f32(result.as_mut_ptr(), refined);
+        }
+        Vec4::new(result[0], result[1], result[2], result[3])
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn recip_simd(&self) -> Vec4 {
+        Vec4::new(1.0 / self.x, 1.0 / self.y, 1.0 / self.z, 1.0 / self.w)
+    }
+
+    /// Approximate per-lane `1 / sqrt(x)`: the hardware estimate refined by one
+    /// Newton-Raphson step (`r * (1.5 - 0.5 * x * r * r)`).
+    #[cfg(target_arch = "x86_64")]
+    pub fn rsqrt_simd(&self) -> Vec4 {
+        let mut result = [0.0; 4];
+        unsafe {
+            let x = _mm_loadu_ps(self.as_ptr());
+            let r = _mm_rsqrt_ps(x);
+            let half_x_r2 = _mm_mul_ps(_mm_mul_ps(_mm_set1_ps(0.5), x), _mm_mul_ps(r, r));
+            let refined = _mm_mul_ps(r, _mm_sub_ps(_mm_set1_ps(1.5), half_x_r2));
+            _mm_storeu_ps(result.as_mut_ptr(), refined);
+        }
+        Vec4::new(result[0], result[1], result[2], result[3])
+    }
+
+    /// Approximate per-lane `1 / sqrt(x)`: the hardware estimate refined by one
+    /// Newton-Raphson step (`r * (1.5 - 0.5 * x * r * r)`).
+    #[cfg(target_arch = "aarch64")]
+    pub fn rsqrt_simd(&self) -> Vec4 {
+        let mut result = [0.0; 4];
+        unsafe {
+            let x = vld1q_f32(self.as_ptr());
+            let r = vrsqrteq_f32(x);
+            let refined = vmulq_f32(r, vrsqrtsq_f32(vmulq_f32(x, r), r));
+            vst1q_f32(result.as_mut_ptr(), refined);
+        }
+        Vec4::new(result[0], result[1], result[2], result[3])
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    pub fn rsqrt_simd(&self) -> Vec4 {
+        Vec4::new(
+            1.0 / self.x.sqrt(),
+            1.0 / self.y.sqrt(),
+            1.0 / self.z.sqrt(),
+            1.0 / self.w.sqrt(),
+        )
+    }
+
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn distance(&self, other: &Vec4) -> f32 {
+        self.distance_squared(other).sqrt()
+    }
+
+    pub fn distance_squared(&self, other: &Vec4) -> f32 {
+        self.sub(other).length_squared()
+    }
+
+    pub fn is_normalized(&self, epsilon: f32) -> bool {
+        math::approx_equal(self.length(), 1.0, epsilon)
+    }
+
+    pub fn unit(&self) -> Vec4 {
+        let len = self.length();
+        if len > 0.0 {
+            self.mul_scalar(1.0 / len)
+        } else {
+            *self
+        }
+    }
+
+    pub fn clamp_length(&self, max: f32) -> Vec4 {
+        let len = self.length();
+        if len > max {
+            self.mul_scalar(max / len)
+        } else {
+            *self
+        }
+    }
+}
+
+/// Interleaves separate x/y/z/w arrays into `out`. All slices must have the same length.
+pub fn soa_to_aos(xs: &[f32], ys: &[f32], zs: &[f32], ws: &[f32], out: &mut [Vec4]) {
+    let n = out.len();
+    assert!(xs.len() == n && ys.len() == n && zs.len() == n && ws.len() == n);
+    for (i, v) in out.iter_mut().enumerate() {
+        *v = Vec4::new(xs[i], ys[i], zs[i], ws[i]);
+    }
+}
+
+/// Splits `points` into separate x/y/z/w arrays. All slices must have the same length.
+pub fn aos_to_soa(points: &[Vec4], xs: &mut [f32], ys: &mut [f32], zs: &mut [f32], ws: &mut [f32]) {
+    let n = points.len();
+    assert!(xs.len() == n && ys.len() == n && zs.len() == n && ws.len() == n);
+    for (i, v) in points.iter().enumerate() {
+        xs[i] = v.x;
+        ys[i] = v.y;
+        zs[i] = v.z;
+        ws[i] = v.w;
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+pub fn sum_vec4(slice: &[Vec4]) -> Vec4 {
+    let mut result = [0.0; 4];
+    unsafe {
+        let mut sum = _mm_setzero_ps();
+        for v in slice {
+            sum = _mm_add_ps(sum, _mm_loadu_ps(v.as_ptr()));
+        }
+        _mm_storeu_ps(result.as_mut_ptr(), sum);
+    }
+    Vec4::new(result[0], result[1], result[2], result[3])
+}
+
+#[cfg(target_arch = "aarch64")]
+pub fn sum_vec4(slice: &[Vec4]) -> Vec4 {
+    let mut result = [0.0; 4];
+    unsafe {
+        let mut sum = vdupq_n_f32(0.0);
+        for v in slice {
+            sum = vaddq_f32(sum, vld1q_f32(v.as_ptr()));
+        }
+        vst1q_f32(result.as_mut_ptr(), sum);
+    }
+    Vec4::new(result[0], result[1], result[2], result[3])
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub fn sum_vec4(slice: &[Vec4]) -> Vec4 {
+    slice
+        .iter()
+        .fold(Vec4::new(0.0, 0.0, 0.0, 0.0), |sum, v| sum.add(v))
+}
+
+/// Operator impls for a vector type. `Add`/`Sub` delegate to the named methods and
+/// also accept a borrowed right-hand side, so existing `a.add(&b)` calls still
+/// resolve. `Mul<Self>` is the component-wise (Hadamard) product.
+macro_rules! impl_vector_ops {
+    ($vec:ident { $($field:ident),+ }) => {
+        impl Add for $vec {
+            type Output = $vec;
+
+            fn add(self, rhs: $vec) -> $vec {
+                $vec::add(&self, &rhs)
+            }
+        }
+
+        impl Add<&$vec> for $vec {
+            type Output = $vec;
+
+            fn add(self, rhs: &$vec) -> $vec {
+                $vec::add(&self, rhs)
+            }
+        }
+
+        impl Sub for $vec {
+            type Output = $vec;
+
+            fn sub(self, rhs: $vec) -> $vec {
+                $vec::sub(&self, &rhs)
+            }
+        }
+
+        impl Sub<&$vec> for $vec {
+            type Output = $vec;
+
+            fn sub(self, rhs: &$vec) -> $vec {
+                $vec::sub(&self, rhs)
+            }
+        }
+
+        impl Neg for $vec {
+            type Output = $vec;
+
+            fn neg(self) -> $vec {
+                $vec { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<f32> for $vec {
+            type Output = $vec;
+
+            fn mul(self, rhs: f32) -> $vec {
+                self.mul_scalar(rhs)
+            }
+        }
+
+        impl Mul<$vec> for f32 {
+            type Output = $vec;
+
+            fn mul(self, rhs: $vec) -> $vec {
+                rhs.mul_scalar(self)
+            }
+        }
+
+        impl Mul for $vec {
+            type Output = $vec;
+
+            fn mul(self, rhs: $vec) -> $vec {
+                $vec { $($field: self.$field * rhs.$field),+ }
+            }
+        }
+
+        impl AddAssign for $vec {
+            fn add_assign(&mut self, rhs: $vec) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $vec {
+            fn sub_assign(&mut self, rhs: $vec) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<f32> for $vec {
+            fn mul_assign(&mut self, rhs: f32) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl MulAssign for $vec {
+            fn mul_assign(&mut self, rhs: $vec) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Vec2 { x, y });
+impl_vector_ops!(Vec3 { x, y, z });
+impl_vector_ops!(Vec4 { x, y, z, w });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec2_creation() {
+        let v = Vec2::new(1.0, 2.0);
+        assert_eq!(v.x, 1.0);
+        assert_eq!(v.y, 2.0);
+    }
+
+    #[test]
+    fn test_vec2_add() {
+        let v1 = Vec2::new(1.0, 2.0);
+        let v2 = Vec2::new(3.0, 4.0);
+        let result = v1.add(&v2);
+        assert_eq!(result, Vec2::new(4.0, 6.0));
+    }
+
+    #[test]
+    fn test_vec2_sub() {
+        let v1 = Vec2::new(5.0, 6.0);
+        let v2 = Vec2::new(2.0, 3.0);
+        let result = v1.sub(&v2);
+        assert_eq!(result, Vec2::new(3.0, 3.0));
+    }
+
+    #[test]
+    fn test_vec2_mul_scalar() {
+        let v = Vec2::new(1.0, 2.0);
+        let result = v.mul_scalar(3.0);
+        assert_eq!(result, Vec2::new(3.0, 6.0));
+    }
+
+    #[test]
+    fn test_vec2_dot() {
+        let v1 = Vec2::new(1.0, 2.0);
+        let v2 = Vec2::new(3.0, 4.0);
+        let result = v1.dot(&v2);
+        assert_eq!(result, 11.0);
+    }
+
+    #[test]
+    fn test_vec2_length() {
+        let v = Vec2::new(3.0, 4.0);
+        let result = v.length();
+        assert_eq!(result, 5.0); // sqrt(3^2 + 4^2) = 5
+    }
+
+    #[test]
+    fn test_vec2_unit() {
+        let v = Vec2::new(3.0, 4.0);
+        let unit_v = v.unit();
+        assert_eq!(unit_v.length(), 1.0);
+    }
+
+    #[test]
+    fn test_vec2_clamp_length() {
+        let v = Vec2::new(6.0, 8.0).clamp_length(5.0);
+        assert_eq!(v, Vec2::new(3.0, 4.0));
+        assert_eq!(Vec2::new(0.3, 0.4).clamp_length(5.0), Vec2::new(0.3, 0.4));
+    }
+
+    #[test]
+    fn test_vec2_lerp_vec() {
+        let a = Vec2::new(0.0, 10.0);
+        let b = Vec2::new(4.0, 20.0);
+        assert_eq!(a.lerp_vec(&b, Vec2::new(0.25, 1.0)), Vec2::new(1.0, 20.0));
+    }
+
+    #[test]
+    fn test_vec2_complex_mul() {
+        let one = Vec2::new(1.0, 0.0);
+        let i = Vec2::new(0.0, 1.0);
+        assert_eq!(one.complex_mul(&i), i);
+        assert_eq!(one.complex_mul(&i).complex_mul(&i), Vec2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn test_vec2_aspect_fit_and_fill() {
+        let content = Vec2::new(160.0, 90.0);
+        let container = Vec2::new(400.0, 300.0);
+        assert_eq!(
+            Vec2::aspect_fit(content, container),
+            Vec2::new(400.0, 225.0)
+        );
+
+        let filled = Vec2::aspect_fill(content, container);
+        assert!((filled.x - 1600.0 / 3.0).abs() < 1e-3);
+        assert!((filled.y - 300.0).abs() < 1e-3);
+
+        let zero = Vec2::new(0.0, 0.0);
+        assert_eq!(Vec2::aspect_fit(Vec2::new(0.0, 9.0), container), zero);
+    }
+
+    #[test]
+    fn test_vec2_rotate_towards() {
+        let v = Vec2::new(2.0, 0.0);
+        let target = Vec2::new(0.0, 5.0);
+        assert_eq!(v.rotate_towards(target, math::PI), Vec2::new(0.0, 2.0));
+
+        let partway = v.rotate_towards(target, 0.25);
+        assert!((partway.length() - 2.0).abs() < math::EPSILON);
+        assert!((partway.y.atan2(partway.x) - 0.25).abs() < math::EPSILON);
+
+        // The shorter way crosses the -x axis instead of going round through +x.
+        let v = Vec2::new(-1.0, 0.1);
+        let stepped = v.rotate_towards(Vec2::new(-1.0, -0.1), 0.05);
+        assert!(stepped.y < 0.1 && stepped.y > 0.0);
+    }
+
+    #[test]
+    fn test_vec2_rotate_towards_negative_step() {
+        let v = Vec2::new(2.0, 0.0);
+        assert_eq!(v.rotate_towards(Vec2::new(0.0, 1.0), -0.5), v);
+    }
+
+    #[test]
+    fn test_vec2_from_angle() {
+        let rotated =
+            Vec2::new(2.0, 0.0).complex_mul(&Vec2::from_angle(std::f32::consts::PI / 2.0));
+        assert!(rotated.x.abs() < math::EPSILON);
+        assert!((rotated.y - 2.0).abs() < math::EPSILON);
+    }
+
+    #[test]
+    fn test_vec2_add_pairs() {
+        let a: Vec<Vec2> = (0..5).map(|i| Vec2::new(i as f32, -(i as f32))).collect();
+        let b: Vec<Vec2> = (0..5).map(|i| Vec2::new(0.5 * i as f32, 2.0)).collect();
+        let mut out = vec![Vec2::new(0.0, 0.0); 5];
+        Vec2::add_pairs(&a, &b, &mut out);
+        for i in 0..5 {
+            assert_eq!(out[i], a[i].add(&b[i]));
+        }
+    }
+
+    #[test]
+    fn test_vec3_creation() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.x, 1.0);
+        assert_eq!(v.y, 2.0);
+        assert_eq!(v.z, 3.0);
+    }
+
+    #[test]
+    fn test_vec3_add() {
+        let v1 = Vec3::new(1.0, 2.0, 3.0);
+        let v2 = Vec3::new(4.0, 5.0, 6.0);
+        let result = v1.add(&v2);
+        assert_eq!(result, Vec3::new(5.0, 7.0, 9.0));
     }
 
     #[test]
@@ -318,6 +1257,396 @@ mod tests {
         assert_eq!(normalized.length(), 1.0);
     }
 
+    #[test]
+    fn test_vec3_clamp_length() {
+        let v = Vec3::new(0.0, 6.0, 8.0).clamp_length(2.5);
+        assert_eq!(v, Vec3::new(0.0, 1.5, 2.0));
+        assert_eq!(
+            Vec3::new(1.0, 0.0, 0.0).clamp_length(2.5),
+            Vec3::new(1.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_vector_operators() {
+        let a = Vec3::new(1.0, -2.0, 3.0);
+        let b = Vec3::new(4.0, 5.0, -6.0);
+        assert_eq!(-a, Vec3::new(-1.0, 2.0, -3.0));
+        assert_eq!(a + b, Vec3::new(5.0, 3.0, -3.0));
+        assert_eq!(a - b, Vec3::new(-3.0, -7.0, 9.0));
+        assert_eq!(2.0 * a, Vec3::new(2.0, -4.0, 6.0));
+        assert_eq!(a * 2.0, 2.0 * a);
+        assert_eq!(a * b, Vec3::new(4.0, -10.0, -18.0));
+
+        let mut v = a;
+        v *= 3.0;
+        assert_eq!(v, Vec3::new(3.0, -6.0, 9.0));
+        v += b;
+        assert_eq!(v, Vec3::new(7.0, -1.0, 3.0));
+        v -= a;
+        assert_eq!(v, Vec3::new(6.0, 1.0, 0.0));
+        v *= b;
+        assert_eq!(v, Vec3::new(24.0, 5.0, -0.0));
+
+        assert_eq!(-Vec2::new(1.0, -2.0), Vec2::new(-1.0, 2.0));
+        assert_eq!(
+            0.5 * Vec2::new(4.0, 2.0) + Vec2::new(1.0, 1.0),
+            Vec2::new(3.0, 2.0)
+        );
+        let w = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(
+            w - w * Vec4::new(1.0, 0.0, 1.0, 0.0),
+            Vec4::new(0.0, 2.0, 0.0, 4.0)
+        );
+        assert_eq!(-w + w, Vec4::new(0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_vec3_div() {
+        let v = Vec3::new(6.0, -3.0, 1.0);
+        assert_eq!(v.div(&Vec3::new(2.0, 3.0, 4.0)), Vec3::new(3.0, -1.0, 0.25));
+
+        let by_zero = v.div(&Vec3::new(0.0, 1.0, 1.0));
+        assert!(by_zero.x.is_infinite());
+    }
+
+    #[test]
+    fn test_vec3_div_safe() {
+        let v = Vec3::new(6.0, 0.0, 1.0);
+        let divisor = Vec3::new(2.0, 0.0, 0.0);
+        assert_eq!(v.div_safe(&divisor, 0.0), Vec3::new(3.0, 0.0, 0.0));
+        assert_eq!(v.div_safe(&divisor, 1.0), Vec3::new(3.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_vec3_dot_simd() {
+        let cases = [
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)),
+            (Vec3::new(-1.5, 0.0, 2.25), Vec3::new(8.0, -3.0, 0.5)),
+            (Vec3::new(0.1, 0.2, 0.3), Vec3::new(-0.3, 0.2, -0.1)),
+            (Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0)),
+        ];
+        for (a, b) in cases {
+            let expected = a.dot(&b);
+            assert!((a.dot_simd(&b) - expected).abs() <= math::epsilon_scaled(expected));
+        }
+    }
+
+    #[test]
+    fn test_length_squared_and_distance() {
+        let origin = Vec3::new(0.0, 0.0, 0.0);
+        let p = Vec3::new(1.0, 2.0, 2.0);
+        assert_eq!(origin.distance_squared(&p), 9.0);
+        assert_eq!(origin.distance(&p), 3.0);
+        assert_eq!(p.length_squared(), 9.0);
+
+        assert_eq!(Vec2::new(3.0, 4.0).length_squared(), 25.0);
+        assert_eq!(Vec2::new(1.0, 1.0).distance(&Vec2::new(4.0, 5.0)), 5.0);
+        assert_eq!(Vec4::new(1.0, 1.0, 1.0, 1.0).length_squared(), 4.0);
+        let w = Vec4::new(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(w.distance_squared(&Vec4::new(2.0, 0.0, 0.0, 2.0)), 8.0);
+    }
+
+    #[test]
+    fn test_vec3_reflect() {
+        let n = Vec3::new(0.0, 1.0, 0.0);
+        assert_eq!(
+            Vec3::new(1.0, -1.0, 0.0).reflect(&n),
+            Vec3::new(1.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_vec3_refract() {
+        let n = Vec3::new(0.0, 1.0, 0.0);
+        let straight = Vec3::new(0.0, -1.0, 0.0);
+        assert_eq!(straight.refract(&n, 1.5), Some(straight));
+
+        // Snell: sin(out) = eta * sin(in).
+        let incident = Vec3::new(0.6, -0.8, 0.0);
+        let out = incident.refract(&n, 0.5).unwrap();
+        assert!((out.length() - 1.0).abs() < math::EPSILON);
+        assert!((out.x - 0.3).abs() < math::EPSILON);
+        assert!(out.y < 0.0);
+
+        assert_eq!(incident.refract(&n, 1.8), None);
+    }
+
+    #[test]
+    fn test_vec3_clamp_length_range() {
+        let short = Vec3::new(0.0, 0.3, 0.4).clamp_length_range(2.0, 5.0);
+        assert!((short.length() - 2.0).abs() < math::EPSILON);
+        assert!(short.same_direction(&Vec3::new(0.0, 3.0, 4.0), math::EPSILON));
+
+        let long = Vec3::new(0.0, 6.0, 8.0).clamp_length_range(2.0, 5.0);
+        assert_eq!(long, Vec3::new(0.0, 3.0, 4.0));
+
+        let inside = Vec3::new(3.0, 0.0, 0.0);
+        assert_eq!(inside.clamp_length_range(2.0, 5.0), inside);
+        let zero = Vec3::new(0.0, 0.0, 0.0);
+        assert_eq!(zero.clamp_length_range(2.0, 5.0), zero);
+    }
+
+    #[test]
+    fn test_vec3_clamp_signed_unit() {
+        assert_eq!(
+            Vec3::new(-1.5, 0.5, 2.0).clamp_signed_unit(),
+            Vec3::new(-1.0, 0.5, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_vec3_abs() {
+        assert_eq!(Vec3::new(-3.0, 0.0, 5.0).abs(), Vec3::new(3.0, 0.0, 5.0));
+    }
+
+    #[test]
+    fn test_vec3_signum() {
+        assert_eq!(
+            Vec3::new(-3.0, 0.0, 5.0).signum(),
+            Vec3::new(-1.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_vec3_map() {
+        let v = Vec3::new(1.0, -2.0, 3.0);
+        assert_eq!(v.map(|x| x * x), Vec3::new(1.0, 4.0, 9.0));
+    }
+
+    #[test]
+    fn test_vec3_zip_map() {
+        let a = Vec3::new(1.0, 5.0, 3.0);
+        let b = Vec3::new(4.0, 2.0, 3.0);
+        assert_eq!(a.zip_map(&b, f32::max), Vec3::new(4.0, 5.0, 3.0));
+    }
+
+    #[test]
+    fn test_vec3_same_direction() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        assert!(x.same_direction(&Vec3::new(5.0, 0.0, 0.0), math::EPSILON));
+        assert!(!x.same_direction(&Vec3::new(0.0, 1.0, 0.0), math::EPSILON));
+        assert!(!x.same_direction(&Vec3::new(-2.0, 0.0, 0.0), math::EPSILON));
+        assert!(!x.same_direction(&Vec3::new(0.0, 0.0, 0.0), math::EPSILON));
+    }
+
+    #[test]
+    fn test_vec3_clamp_to_sphere() {
+        let center = Vec3::new(1.0, 1.0, 1.0);
+        let far = Vec3::new(11.0, 1.0, 1.0).clamp_to_sphere(center, 2.0);
+        assert_eq!(far, Vec3::new(3.0, 1.0, 1.0));
+        assert!((far.sub(&center).length() - 2.0).abs() < math::EPSILON);
+
+        let inside = Vec3::new(1.5, 1.0, 0.5);
+        assert_eq!(inside.clamp_to_sphere(center, 2.0), inside);
+    }
+
+    #[test]
+    fn test_vec3_lerp() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(2.0, 4.0, -6.0);
+        assert_eq!(a.lerp(&b, 0.5), Vec3::new(1.0, 2.0, -3.0));
+        assert_eq!(a.lerp(&b, 2.0), b);
+        assert_eq!(a.lerp(&b, -1.0), a);
+    }
+
+    #[test]
+    fn test_vec3_lerp_unclamped() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(2.0, 4.0, -6.0);
+        assert_eq!(a.lerp_unclamped(&b, 2.0), Vec3::new(4.0, 8.0, -12.0));
+        assert_eq!(a.lerp_unclamped(&b, -0.5), Vec3::new(-1.0, -2.0, 3.0));
+    }
+
+    #[test]
+    fn test_vec3_sanitize() {
+        let fallback = Vec3::new(0.0, 0.0, 0.0);
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.sanitize(fallback), v);
+        assert_eq!(Vec3::new(1.0, f32::NAN, 3.0).sanitize(fallback), fallback);
+        assert_eq!(
+            Vec3::new(f32::NEG_INFINITY, 0.0, 0.0).sanitize(fallback),
+            fallback
+        );
+    }
+
+    #[test]
+    fn test_vec3_reflect_point() {
+        let p = Vec3::new(3.0, 0.0, 0.0);
+        assert_eq!(
+            p.reflect_point(Vec3::new(1.0, 0.0, 0.0)),
+            Vec3::new(-1.0, 0.0, 0.0)
+        );
+        let p = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(
+            p.reflect_point(Vec3::new(0.0, 0.0, 0.0)),
+            Vec3::new(-1.0, -2.0, -3.0)
+        );
+    }
+
+    #[test]
+    fn test_vec3_barycentric() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(2.0, 0.0, 0.0);
+        let c = Vec3::new(0.0, 2.0, 0.0);
+        assert_eq!(a.barycentric(&a, &b, &c), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            Vec3::new(1.0, 1.0, 0.0).barycentric(&a, &b, &c),
+            Vec3::new(0.0, 0.5, 0.5)
+        );
+        assert_eq!(
+            Vec3::new(0.5, 0.5, 0.0).barycentric_safe(&a, &b, &c),
+            Some(Vec3::new(0.5, 0.25, 0.25))
+        );
+    }
+
+    #[test]
+    fn test_vec3_barycentric_safe_degenerate() {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 1.0, 1.0);
+        let c = Vec3::new(3.0, 3.0, 3.0);
+        let p = Vec3::new(2.0, 2.0, 2.0);
+        assert_eq!(p.barycentric_safe(&a, &b, &c), None);
+        assert_eq!(p.barycentric_safe(&a, &a, &a), None);
+    }
+
+    #[test]
+    fn test_vec3_pack_normal_1010102() {
+        let normals = [
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(1.0, 2.0, -3.0).normalize(),
+            Vec3::new(-0.3, 0.4, 0.2).normalize(),
+        ];
+        for n in normals {
+            let unpacked = Vec3::unpack_normal_1010102(n.pack_normal_1010102());
+            assert!(unpacked.sub(&n).length() < 2.0 / 1023.0);
+        }
+        assert_eq!(Vec3::new(-1.0, -1.0, -1.0).pack_normal_1010102(), 0);
+        assert_eq!(Vec3::new(1.0, 1.0, 1.0).pack_normal_1010102(), 0x3fff_ffff);
+    }
+
+    #[test]
+    fn test_vec3_nlerp() {
+        let a = Vec3::new(1.0, 0.0, 0.0);
+        let b = Vec3::new(0.0, 1.0, 0.0);
+        assert_eq!(a.nlerp(&b, 0.0), a);
+        assert_eq!(a.nlerp(&b, 1.0), b);
+        let halfway = a.nlerp(&b, 0.5);
+        assert!((halfway.length() - 1.0).abs() < 1e-6);
+        assert!((halfway.x - halfway.y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_vec3_lerp_vec() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(5.0, 6.0, 7.0);
+        let t = Vec3::new(0.0, 0.5, 1.0);
+        assert_eq!(a.lerp_vec(&b, t), Vec3::new(1.0, 4.0, 7.0));
+    }
+
+    #[test]
+    fn test_vec3_cylindrical_round_trip() {
+        let v = Vec3::from_cylindrical(2.0, 1.2, -3.0);
+        let (radius, theta, height) = v.to_cylindrical();
+        assert!((radius - 2.0).abs() < math::EPSILON);
+        assert!((theta - 1.2).abs() < math::EPSILON);
+        assert_eq!(height, -3.0);
+
+        let p = Vec3::new(-1.0, 4.0, -2.5);
+        let (radius, theta, height) = p.to_cylindrical();
+        let back = Vec3::from_cylindrical(radius, theta, height);
+        assert!(back.sub(&p).length() < math::EPSILON);
+    }
+
+    #[test]
+    fn test_vec3_major_axis() {
+        assert_eq!(Vec3::new(3.0, -1.0, 2.0).major_axis(), 0);
+        assert_eq!(Vec3::new(0.5, -4.0, 2.0).major_axis(), 1);
+        assert_eq!(Vec3::new(0.5, 1.0, -2.0).major_axis(), 2);
+    }
+
+    #[test]
+    fn test_vec3_cell() {
+        assert_eq!(Vec3::new(0.5, 1.5, 2.5).cell(1.0), (0, 1, 2));
+        assert_eq!(Vec3::new(-0.5, 3.9, -4.1).cell(2.0), (-1, 1, -3));
+    }
+
+    #[test]
+    fn test_vec3_permute() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.permute([2, 0, 1]), Vec3::new(3.0, 1.0, 2.0));
+        assert_eq!(v.yzx(), Vec3::new(2.0, 3.0, 1.0));
+        assert_eq!(v.zxy(), Vec3::new(3.0, 1.0, 2.0));
+        assert_eq!(v.yzx().zxy(), v);
+    }
+
+    #[test]
+    fn test_vec3_bit_hash() {
+        let v = Vec3::new(1.0, -2.0, 0.5);
+        assert_eq!(v.bit_hash(), Vec3::new(1.0, -2.0, 0.5).bit_hash());
+        assert_ne!(v.bit_hash(), Vec3::new(1.0, -2.0, 0.500001).bit_hash());
+        assert_ne!(v.bit_hash(), Vec3::new(-2.0, 1.0, 0.5).bit_hash());
+        assert_eq!(
+            Vec3::new(0.0, 1.0, 0.0).bit_hash(),
+            Vec3::new(-0.0, 1.0, -0.0).bit_hash()
+        );
+    }
+
+    #[test]
+    fn test_vec3_snap_to_nearest_axis() {
+        let v = Vec3::new(0.2, 0.9, -0.3);
+        assert_eq!(v.snap_to_nearest_axis(), Vec3::new(0.0, 1.0, 0.0));
+
+        let v = Vec3::new(0.1, 0.2, -0.8);
+        assert_eq!(v.snap_to_nearest_axis(), Vec3::new(0.0, 0.0, -1.0));
+
+        let v = Vec3::new(0.5, -0.5, 0.0);
+        assert_eq!(v.snap_to_nearest_axis(), Vec3::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_vec3_snap_to_nearest_axis_degenerate() {
+        let zero = Vec3::new(0.0, 0.0, 0.0);
+        assert_eq!(zero.snap_to_nearest_axis(), zero);
+        assert_eq!(Vec3::new(f32::NAN, 1.0, 0.0).snap_to_nearest_axis(), zero);
+    }
+
+    #[test]
+    fn test_vec3_min_max() {
+        let a = Vec3::new(1.0, 5.0, -2.0);
+        let b = Vec3::new(3.0, 2.0, -4.0);
+        assert_eq!(a.min(&b), Vec3::new(1.0, 2.0, -4.0));
+        assert_eq!(a.max(&b), Vec3::new(3.0, 5.0, -2.0));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut corners = Vec::new();
+        for i in 0..8 {
+            corners.push(Vec3::new(
+                (i & 1) as f32,
+                ((i >> 1) & 1) as f32,
+                ((i >> 2) & 1) as f32,
+            ));
+        }
+        assert_eq!(
+            bounds(&corners),
+            Some((Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0)))
+        );
+        assert_eq!(bounds(&[]), None);
+    }
+
+    #[test]
+    fn test_frame_handedness() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        assert_eq!(frame_handedness(x, y, z), 1);
+        assert_eq!(frame_handedness(x, y, z.mul_scalar(-1.0)), -1);
+        assert_eq!(frame_handedness(x, y, x.add(&y)), 0);
+    }
+
     #[test]
     fn test_vec4_creation() {
         let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
@@ -366,6 +1695,141 @@ mod tests {
         assert_eq!(result, 70.0);
     }
 
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    #[test]
+    fn test_vec4_dot_simd_fallback() {
+        let v1 = Vec4::new(1.0, -2.0, 0.5, 4.0);
+        let v2 = Vec4::new(3.0, 1.0, -2.0, 0.25);
+        assert_eq!(v1.dot_simd(&v2), v1.dot(&v2));
+    }
+
+    #[test]
+    fn test_vec4_dot_auto() {
+        for i in 0..64 {
+            let f = i as f32;
+            let a = Vec4::new(
+                f * 0.5 - 7.0,
+                (f * 1.3).sin() * 4.0,
+                3.0 - f * 0.25,
+                f % 5.0,
+            );
+            let b = Vec4::new((f * 0.7).cos(), f * 0.1, -2.5 + f % 3.0, 9.0 - f * 0.3);
+            let expected = a.dot(&b);
+            assert!((a.dot_auto(&b) - expected).abs() <= math::epsilon_scaled(expected));
+        }
+    }
+
+    #[test]
+    fn test_vec4_clamp_length() {
+        let v = Vec4::new(2.0, 4.0, 4.0, 0.0).clamp_length(3.0);
+        assert_eq!(v, Vec4::new(1.0, 2.0, 2.0, 0.0));
+        assert_eq!(v.length(), 3.0);
+    }
+
+    #[test]
+    fn test_vec4_recip_and_rsqrt_simd() {
+        let v = Vec4::new(0.5, 3.0, 7.25, 10.0);
+        let recip = v.recip_simd();
+        let rsqrt = v.rsqrt_simd();
+        let lanes = |v: Vec4| [v.x, v.y, v.z, v.w];
+        for ((x, r), s) in lanes(v).into_iter().zip(lanes(recip)).zip(lanes(rsqrt)) {
+            assert!((r - 1.0 / x).abs() < 1e-4);
+            assert!((s - 1.0 / x.sqrt()).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_vec4_is_normalized() {
+        let v = Vec4::new(1.0, 2.0, 2.0, 4.0).unit();
+        assert!(v.is_normalized(math::EPSILON));
+        assert!(!v.mul_scalar(1.01).is_normalized(math::EPSILON));
+        assert!(!Vec4::new(0.0, 0.0, 0.0, 0.0).is_normalized(math::EPSILON));
+    }
+
+    #[test]
+    fn test_vec4_horizontal_sum() {
+        assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0).horizontal_sum(), 10.0);
+        assert_eq!(Vec4::new(-8.0, 0.5, 16.0, -0.25).horizontal_sum(), 8.25);
+    }
+
+    #[test]
+    fn test_vec4_horizontal_product() {
+        assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0).horizontal_product(), 24.0);
+    }
+
+    #[test]
+    fn test_soa_aos_round_trip() {
+        let xs = [1.0, 2.0, 3.0];
+        let ys = [4.0, 5.0, 6.0];
+        let zs = [7.0, 8.0, 9.0];
+        let ws = [1.0, 0.0, 1.0];
+        let mut points = [Vec4::new(0.0, 0.0, 0.0, 0.0); 3];
+        soa_to_aos(&xs, &ys, &zs, &ws, &mut points);
+        assert_eq!(points[1], Vec4::new(2.0, 5.0, 8.0, 0.0));
+
+        let (mut xs2, mut ys2, mut zs2, mut ws2) = ([0.0; 3], [0.0; 3], [0.0; 3], [0.0; 3]);
+        aos_to_soa(&points, &mut xs2, &mut ys2, &mut zs2, &mut ws2);
+        assert_eq!((xs2, ys2, zs2, ws2), (xs, ys, zs, ws));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_soa_to_aos_length_mismatch() {
+        let mut points = [Vec4::new(0.0, 0.0, 0.0, 0.0); 2];
+        soa_to_aos(&[1.0], &[2.0], &[3.0], &[4.0], &mut points);
+    }
+
+    #[test]
+    fn test_sum_vec4() {
+        let mut seed: u32 = 12345;
+        let mut next = || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            (seed >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0
+        };
+        let values: Vec<Vec4> = (0..37)
+            .map(|_| Vec4::new(next(), next(), next(), next()))
+            .collect();
+        let expected = values
+            .iter()
+            .fold(Vec4::new(0.0, 0.0, 0.0, 0.0), |sum, v| sum.add(v));
+        let result = sum_vec4(&values);
+        assert!(result.sub(&expected).length() < 1e-4);
+    }
+
+    #[test]
+    fn test_sum_vec4_empty() {
+        assert_eq!(sum_vec4(&[]), Vec4::new(0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_vec4_max_min_component() {
+        let v = Vec4::new(1.0, 4.0, 2.0, 3.0);
+        assert_eq!(v.max_component(), 4.0);
+        assert_eq!(v.min_component(), 1.0);
+        assert_eq!(v.max_component_simd(), 4.0);
+        assert_eq!(v.min_component_simd(), 1.0);
+
+        let v = Vec4::new(-2.0, -7.0, 5.5, 0.0);
+        assert_eq!(v.max_component_simd(), 5.5);
+        assert_eq!(v.min_component_simd(), -7.0);
+    }
+
+    #[test]
+    fn test_vec4_mix() {
+        let a = Vec4::new(0.0, 10.0, -4.0, 1.0);
+        let b = Vec4::new(8.0, 20.0, 4.0, 3.0);
+        let weights = Vec4::new(0.0, 0.25, 0.5, 1.0);
+        let result = a.mix(&b, &weights);
+        let expected = Vec4::new(
+            a.x * (1.0 - weights.x) + b.x * weights.x,
+            a.y * (1.0 - weights.y) + b.y * weights.y,
+            a.z * (1.0 - weights.z) + b.z * weights.z,
+            a.w * (1.0 - weights.w) + b.w * weights.w,
+        );
+        assert!(result.sub(&expected).length() < 1e-6);
+        assert_eq!(result, Vec4::new(0.0, 12.5, 0.0, 3.0));
+    }
+
     #[test]
     fn test_vec4_length() {
         let v = Vec4::new(1.0, 2.0, 2.0, 0.0);